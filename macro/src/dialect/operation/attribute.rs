@@ -43,13 +43,22 @@ static ATTRIBUTE_TYPES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(||
     }
 
     initialize_attributes!(
+        AffineMapAttr => AffineMapAttribute,
         ArrayAttr => ArrayAttribute,
         Attribute => Attribute,
+        DenseBoolArrayAttr => DenseBoolArrayAttribute,
         DenseElementsAttr => DenseElementsAttribute,
+        DenseF32ArrayAttr => DenseF32ArrayAttribute,
+        DenseF64ArrayAttr => DenseF64ArrayAttribute,
+        DenseI8ArrayAttr => DenseI8ArrayAttribute,
+        DenseI16ArrayAttr => DenseI16ArrayAttribute,
         DenseI32ArrayAttr => DenseI32ArrayAttribute,
+        DenseI64ArrayAttr => DenseI64ArrayAttribute,
         FlatSymbolRefAttr => FlatSymbolRefAttribute,
         FloatAttr => FloatAttribute,
         IntegerAttr => IntegerAttribute,
+        IntegerSetAttr => IntegerSetAttribute,
+        StridedLayoutAttr => StridedLayoutAttribute,
         StringAttr => StringAttribute,
         TypeAttr => TypeAttribute,
     );
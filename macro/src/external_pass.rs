@@ -0,0 +1,169 @@
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use std::error::Error;
+use syn::{
+    bracketed, ext::IdentExt, punctuated::Punctuated, Attribute, Data, DeriveInput, Expr, ExprLit,
+    Fields, Lit, LitStr, Meta, Token,
+};
+
+const ATTRIBUTE_NAME: &str = "pass";
+
+pub fn generate(input: DeriveInput) -> Result<TokenStream, Box<dyn Error>> {
+    if !input.generics.params.is_empty() {
+        return Err("external pass must not have generic parameters".into());
+    }
+
+    let identifier = &input.ident;
+    let name = identifier.to_string();
+    let mut argument = name.to_case(Case::Kebab);
+    let mut operation_name = String::new();
+    let mut dialects = vec![];
+
+    for attribute in &input.attrs {
+        if !attribute.path().is_ident(ATTRIBUTE_NAME) {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("argument") {
+                argument = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("op") {
+                operation_name = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("dialects") {
+                let value = meta.value()?;
+                let content;
+                bracketed!(content in value);
+                dialects.extend(Punctuated::<Ident, Token![,]>::parse_terminated(&content)?);
+            } else {
+                return Err(meta.error("unknown pass attribute"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let description = parse_documentation(&input.attrs);
+    let (option_names, option_fields) = parse_options(&input.data)?;
+
+    Ok(quote! {
+        impl #identifier {
+            /// Creates a pass with default options.
+            pub fn create() -> melior::pass::Pass {
+                Self::default().into_pass()
+            }
+
+            /// Creates a pass with options in a textual form.
+            pub fn create_with_options(options: &str) -> Result<melior::pass::Pass, melior::Error> {
+                let mut pass = Self::default();
+
+                for (name, value) in melior::pass::options::parse_options(options)? {
+                    match name.as_str() {
+                        #(#option_names => {
+                            pass.#option_fields = value.parse().map_err(|_| {
+                                melior::Error::ParsePassPipeline(format!(
+                                    "invalid value for option {} of pass {}: {}",
+                                    name, #argument, value
+                                ))
+                            })?;
+                        })*
+                        _ => {
+                            return Err(melior::Error::ParsePassPipeline(format!(
+                                "unknown option for pass {}: {}",
+                                #argument, name
+                            )))
+                        }
+                    }
+                }
+
+                Ok(pass.into_pass())
+            }
+
+            /// Converts a pass configuration into a pass.
+            pub fn into_pass(self) -> melior::pass::Pass {
+                #[repr(align(8))]
+                struct PassId;
+
+                static PASS_ID: PassId = PassId;
+
+                let options: Vec<(&str, String)> = vec![
+                    #((#option_names, self.#option_fields.to_string())),*
+                ];
+
+                melior::pass::create_external(
+                    self,
+                    melior::ir::r#type::TypeId::create(&PASS_ID),
+                    #name,
+                    #argument,
+                    #description,
+                    #operation_name,
+                    &[#(melior::dialect::DialectHandle::#dialects()),*],
+                )
+                .with_options(&melior::pass::options::print_options(&options))
+            }
+
+            /// Registers a pass.
+            pub fn register() {
+                melior::utility::register_external_pass(#argument, Self::create_with_options);
+            }
+        }
+    }
+    .into())
+}
+
+fn parse_documentation(attributes: &[Attribute]) -> String {
+    attributes
+        .iter()
+        .filter_map(|attribute| match &attribute.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(string),
+                    ..
+                }) => Some(string.value().trim().to_owned()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_owned()
+}
+
+fn parse_options(data: &Data) -> Result<(Vec<String>, Vec<Ident>), Box<dyn Error>> {
+    let Data::Struct(data) = data else {
+        return Err("external pass must be a struct".into());
+    };
+    let mut names = vec![];
+    let mut fields = vec![];
+
+    if let Fields::Named(named) = &data.fields {
+        for field in &named.named {
+            let mut skipped = false;
+
+            for attribute in &field.attrs {
+                if attribute.path().is_ident(ATTRIBUTE_NAME) {
+                    attribute.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("skip") {
+                            skipped = true;
+
+                            Ok(())
+                        } else {
+                            Err(meta.error("unknown pass option attribute"))
+                        }
+                    })?;
+                }
+            }
+
+            if !skipped {
+                let identifier = field.ident.clone().expect("named field");
+
+                names.push(identifier.unraw().to_string().to_case(Case::Kebab));
+                fields.push(identifier);
+            }
+        }
+    }
+
+    Ok((names, fields))
+}
@@ -1,5 +1,6 @@
 mod attribute;
 mod dialect;
+mod external_pass;
 mod operation;
 mod parse;
 mod pass;
@@ -11,7 +12,7 @@ use parse::{DialectOperationSet, IdentifierList, PassSet};
 use proc_macro::TokenStream;
 use quote::quote;
 use std::error::Error;
-use syn::parse_macro_input;
+use syn::{parse_macro_input, DeriveInput};
 
 /// Generates a dialect module from a TableGen file.
 ///
@@ -89,6 +90,50 @@ pub fn passes(stream: TokenStream) -> TokenStream {
     }))
 }
 
+/// Derives constructors and registration of an external pass.
+///
+/// A pass type must implement `Default` and `RunExternalPass`. Its named
+/// fields are pass options parsed by `FromStr` from their kebab-case names
+/// unless they are marked with `#[pass(skip)]`. Options are printed by
+/// `Display` in pass pipelines. A pass description is taken from a doc
+/// comment.
+///
+/// # Examples
+///
+/// ```rust
+/// use melior::{
+///     ir::OperationRef,
+///     pass::{ExternalPass, RunExternalPass},
+///     ContextRef,
+/// };
+///
+/// /// Dumps operations.
+/// #[derive(Clone, Default, melior::ExternalPass)]
+/// #[pass(argument = "dump-operations", op = "func.func", dialects = [func])]
+/// struct DumpPass {
+///     verbose: bool,
+/// }
+///
+/// impl<'c> RunExternalPass<'c> for DumpPass {
+///     fn initialize(&mut self, _context: ContextRef<'c>) {}
+///
+///     fn run(&mut self, operation: OperationRef<'c, '_>, _pass: ExternalPass<'_>) {
+///         if self.verbose {
+///             operation.dump();
+///         }
+///     }
+/// }
+///
+/// DumpPass::register();
+/// let pass = DumpPass::create_with_options("verbose=true").unwrap();
+/// ```
+#[proc_macro_derive(ExternalPass, attributes(pass))]
+pub fn external_pass(stream: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(stream as DeriveInput);
+
+    convert_result(external_pass::generate(input))
+}
+
 fn convert_result(result: Result<TokenStream, Box<dyn Error>>) -> TokenStream {
     result.unwrap_or_else(|error| {
         let message = error.to_string();
@@ -2,8 +2,10 @@ use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 
 static NAME_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"(bf_16|f_16|f_32|f_64|i_8|i_16|i_32|i_64|float_8_e_[0-9]_m_[0-9](_fn)?)"#)
-        .unwrap()
+    Regex::new(
+        r#"(bf_16|f_16|f_32|f_64|i_8|i_16|i_32|i_64|float_8_e_[0-9]_m_[0-9](_b_11)?(_fnuz|_fn)?)"#,
+    )
+    .unwrap()
 });
 
 pub fn map_name(name: &str) -> String {
@@ -34,5 +36,7 @@ mod tests {
         assert_eq!(map_name("f_64"), "f64");
         assert_eq!(map_name("float_8_e_5_m_2"), "float8e5m2");
         assert_eq!(map_name("float_8_e_4_m_3_fn"), "float8e4m3fn");
+        assert_eq!(map_name("float_8_e_5_m_2_fnuz"), "float8e5m2fnuz");
+        assert_eq!(map_name("float_8_e_4_m_3_b_11_fnuz"), "float8e4m3b11fnuz");
     }
 }
@@ -0,0 +1,131 @@
+mod utility;
+
+use melior::{
+    ir::{Module, OperationRef},
+    pass::{ExternalPass, PassManager, RunExternalPass},
+    utility::parse_pass_pipeline,
+    ContextRef, Error,
+};
+use std::cell::Cell;
+use utility::*;
+
+thread_local! {
+    static COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts functions.
+#[derive(Clone, Debug, Default, melior::ExternalPass)]
+#[pass(argument = "count-functions", op = "func.func", dialects = [func])]
+struct CountFunctions {
+    increment: usize,
+    #[pass(skip)]
+    initialized: bool,
+}
+
+impl<'c> RunExternalPass<'c> for CountFunctions {
+    fn initialize(&mut self, _context: ContextRef<'c>) {
+        self.initialized = true;
+    }
+
+    fn run(&mut self, _operation: OperationRef<'c, '_>, _pass: ExternalPass<'_>) {
+        assert!(self.initialized);
+
+        COUNT.with(|count| count.set(count.get() + self.increment));
+    }
+}
+
+fn run_count_functions(manager: &PassManager, module: &mut Module) -> usize {
+    COUNT.with(|count| count.set(0));
+    manager.run(module).unwrap();
+    COUNT.with(Cell::get)
+}
+
+#[test]
+fn create() {
+    let context = create_test_context();
+    context.enable_multi_threading(false);
+    let mut module = Module::parse(&context, "func.func @foo() { return }").unwrap();
+
+    let manager = PassManager::new(&context);
+    manager.nested_under("func.func").add_pass(
+        CountFunctions {
+            increment: 3,
+            ..Default::default()
+        }
+        .into_pass(),
+    );
+
+    assert_eq!(run_count_functions(&manager, &mut module), 3);
+
+    let manager = PassManager::new(&context);
+    manager
+        .nested_under("func.func")
+        .add_pass(CountFunctions::create());
+
+    assert_eq!(run_count_functions(&manager, &mut module), 0);
+}
+
+#[test]
+fn register() {
+    let context = create_test_context();
+    context.enable_multi_threading(false);
+    let mut module = Module::parse(
+        &context,
+        "func.func @foo() { return } func.func @bar() { return }",
+    )
+    .unwrap();
+
+    CountFunctions::register();
+
+    let manager = PassManager::new(&context);
+    parse_pass_pipeline(
+        manager.as_operation_pass_manager(),
+        "builtin.module(func.func(count-functions{increment=2}))",
+    )
+    .unwrap();
+
+    assert_eq!(
+        manager.to_string(),
+        "builtin.module(func.func(count-functions{increment=2}))"
+    );
+    assert_eq!(run_count_functions(&manager, &mut module), 4);
+}
+
+#[test]
+fn round_trip_registered_pass() {
+    let context = create_test_context();
+    context.enable_multi_threading(false);
+    let mut module = Module::parse(&context, "func.func @foo() { return }").unwrap();
+
+    CountFunctions::register();
+
+    let manager = PassManager::new_on_operation(&context, "builtin.module");
+    manager.nested_under("func.func").add_pass(
+        CountFunctions {
+            increment: 5,
+            ..Default::default()
+        }
+        .into_pass(),
+    );
+
+    let other = PassManager::new(&context);
+    parse_pass_pipeline(other.as_operation_pass_manager(), &manager.to_string()).unwrap();
+
+    assert_eq!(
+        other.to_string(),
+        "builtin.module(func.func(count-functions{increment=5}))"
+    );
+    assert_eq!(run_count_functions(&other, &mut module), 5);
+}
+
+#[test]
+fn create_with_invalid_options() {
+    assert!(matches!(
+        CountFunctions::create_with_options("increment=foo"),
+        Err(Error::ParsePassPipeline(_))
+    ));
+    assert!(matches!(
+        CountFunctions::create_with_options("decrement=1"),
+        Err(Error::ParsePassPipeline(_))
+    ));
+}
@@ -1,6 +1,7 @@
 use crate::{
     diagnostic::{Diagnostic, DiagnosticHandlerId},
     dialect::{Dialect, DialectRegistry},
+    ir::attribute::ResourceBlob,
     logical_result::LogicalResult,
     string_ref::StringRef,
 };
@@ -10,9 +11,15 @@ use mlir_sys::{
     mlirContextEqual, mlirContextGetAllowUnregisteredDialects, mlirContextGetNumLoadedDialects,
     mlirContextGetNumRegisteredDialects, mlirContextGetOrLoadDialect,
     mlirContextIsRegisteredOperation, mlirContextLoadAllAvailableDialects,
-    mlirContextSetAllowUnregisteredDialects, MlirContext, MlirDiagnostic, MlirLogicalResult,
+    mlirContextSetAllowUnregisteredDialects, MlirAttribute, MlirContext, MlirDiagnostic,
+    MlirLogicalResult,
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    ffi::c_void,
+    marker::PhantomData,
 };
-use std::{ffi::c_void, marker::PhantomData};
 
 /// A context of IR, dialects, and passes.
 ///
@@ -21,6 +28,11 @@ use std::{ffi::c_void, marker::PhantomData};
 #[derive(Debug)]
 pub struct Context {
     raw: MlirContext,
+    // TODO Use `mlirContextIsMultithreadingEnabled` when we upgrade to MLIR 18.
+    multi_threading: Cell<bool>,
+    // Owners of blobs of dense resource elements attributes, keyed by addresses
+    // of the attributes.
+    resource_blobs: RefCell<HashMap<usize, ResourceBlob>>,
 }
 
 impl Context {
@@ -28,6 +40,8 @@ impl Context {
     pub fn new() -> Self {
         Self {
             raw: unsafe { mlirContextCreate() },
+            multi_threading: Cell::new(true),
+            resource_blobs: Default::default(),
         }
     }
 
@@ -60,9 +74,15 @@ impl Context {
 
     /// Enables multi-threading.
     pub fn enable_multi_threading(&self, enabled: bool) {
+        self.multi_threading.set(enabled);
+
         unsafe { mlirContextEnableMultithreading(self.raw, enabled) }
     }
 
+    pub(crate) fn is_multi_threading_enabled(&self) -> bool {
+        self.multi_threading.get()
+    }
+
     /// Returns `true` if unregistered dialects are allowed.
     pub fn allow_unregistered_dialects(&self) -> bool {
         unsafe { mlirContextGetAllowUnregisteredDialects(self.raw) }
@@ -116,6 +136,21 @@ impl Context {
         unsafe { mlirContextDetachDiagnosticHandler(self.to_raw(), id.to_raw()) }
     }
 
+    pub(crate) fn add_resource_blob(&self, attribute: MlirAttribute, blob: ResourceBlob) {
+        self.resource_blobs
+            .borrow_mut()
+            .insert(attribute.ptr as usize, blob);
+    }
+
+    pub(crate) fn resource_blob(&self, attribute: MlirAttribute) -> Option<&[u8]> {
+        // Blobs are never removed until a context is dropped and their data do
+        // not move with their owners.
+        self.resource_blobs
+            .borrow()
+            .get(&(attribute.ptr as usize))
+            .map(|blob| unsafe { blob.as_bytes() })
+    }
+
     pub(crate) fn to_ref(&self) -> ContextRef {
         unsafe { ContextRef::from_raw(self.to_raw()) }
     }
@@ -123,6 +158,7 @@ impl Context {
 
 impl Drop for Context {
     fn drop(&mut self) {
+        // Blobs are dropped after the context is destroyed.
         unsafe { mlirContextDestroy(self.raw) };
     }
 }
@@ -238,6 +274,8 @@ mod tests {
         let context = Context::new();
 
         context.enable_multi_threading(false);
+
+        assert!(!context.is_multi_threading_enabled());
     }
 
     #[test]
@@ -6,7 +6,7 @@ use crate::{
             DenseElementsAttribute, DenseI32ArrayAttribute, IntegerAttribute, StringAttribute,
         },
         operation::OperationBuilder,
-        r#type::RankedTensorType,
+        r#type::{Dimension, RankedTensorType},
         Block, Identifier, Location, Operation, Type, Value,
     },
     Context, Error,
@@ -97,7 +97,12 @@ pub fn switch<'c>(
             (
                 Identifier::new(context, "case_values"),
                 DenseElementsAttribute::new(
-                    RankedTensorType::new(&[case_values.len() as u64], flag_type, None).into(),
+                    RankedTensorType::new(
+                        &[Dimension::Static(case_values.len() as u64)],
+                        flag_type,
+                        None,
+                    )?
+                    .into(),
                     &case_values
                         .iter()
                         .map(|value| IntegerAttribute::new(flag_type, *value).into())
@@ -9,6 +9,10 @@ use mlir_sys::{
     mlirGetDialectHandle__sparse_tensor__, mlirGetDialectHandle__tensor__, MlirDialectHandle,
 };
 
+// TODO Add an `irdl` dialect handle and loading of IRDL dialect definitions
+// once we depend on MLIR 18. Its C API is the first to expose
+// `mlirGetDialectHandle__irdl__` and `mlirLoadIRDLDialects`.
+
 /// A dialect handle.
 #[derive(Clone, Copy, Debug)]
 pub struct DialectHandle {
@@ -251,7 +251,7 @@ mod tests {
         dialect::{func, index},
         ir::{
             attribute::{DenseElementsAttribute, StringAttribute, TypeAttribute},
-            r#type::{FunctionType, IntegerType, RankedTensorType},
+            r#type::{Dimension, FunctionType, IntegerType, RankedTensorType},
             Block, Module, Region, Type,
         },
         test::create_test_context,
@@ -294,7 +294,7 @@ mod tests {
         compile_operation("alloc", &context, |block| {
             let memref = block.append_operation(alloc(
                 &context,
-                MemRefType::new(Type::index(&context), &[], None, None),
+                MemRefType::new(Type::index(&context), &[], None, None).unwrap(),
                 &[],
                 &[],
                 None,
@@ -312,7 +312,8 @@ mod tests {
         compile_operation("realloc", &context, |block| {
             let memref = block.append_operation(alloc(
                 &context,
-                MemRefType::new(Type::index(&context), &[8], None, None),
+                MemRefType::new(Type::index(&context), &[Dimension::Static(8)], None, None)
+                    .unwrap(),
                 &[],
                 &[],
                 None,
@@ -322,7 +323,8 @@ mod tests {
                 &context,
                 memref.result(0).unwrap().into(),
                 None,
-                MemRefType::new(Type::index(&context), &[42], None, None),
+                MemRefType::new(Type::index(&context), &[Dimension::Static(42)], None, None)
+                    .unwrap(),
                 None,
                 location,
             ));
@@ -337,7 +339,7 @@ mod tests {
         compile_operation("alloca", &context, |block| {
             block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::index(&context), &[], None, None),
+                MemRefType::new(Type::index(&context), &[], None, None).unwrap(),
                 &[],
                 &[],
                 None,
@@ -354,7 +356,13 @@ mod tests {
         compile_operation("cast", &context, |block| {
             let memref = block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::float64(&context), &[42], None, None),
+                MemRefType::new(
+                    Type::float64(&context),
+                    &[Dimension::Static(42)],
+                    None,
+                    None,
+                )
+                .unwrap(),
                 &[],
                 &[],
                 None,
@@ -380,7 +388,8 @@ mod tests {
         compile_operation("dim", &context, |block| {
             let memref = block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::index(&context), &[1], None, None),
+                MemRefType::new(Type::index(&context), &[Dimension::Static(1)], None, None)
+                    .unwrap(),
                 &[],
                 &[],
                 None,
@@ -406,7 +415,7 @@ mod tests {
         let context = create_test_context();
         let location = Location::unknown(&context);
         let module = Module::new(location);
-        let mem_ref_type = MemRefType::new(Type::index(&context), &[], None, None);
+        let mem_ref_type = MemRefType::new(Type::index(&context), &[], None, None).unwrap();
 
         module.body().append_operation(global(
             &context,
@@ -451,7 +460,7 @@ mod tests {
             &context,
             "foo",
             None,
-            MemRefType::new(Type::index(&context), &[], None, None),
+            MemRefType::new(Type::index(&context), &[], None, None).unwrap(),
             None,
             false,
             None,
@@ -473,10 +482,10 @@ mod tests {
             &context,
             "foo",
             Some("private"),
-            MemRefType::new(r#type, &[], None, None),
+            MemRefType::new(r#type, &[], None, None).unwrap(),
             Some(
                 DenseElementsAttribute::new(
-                    RankedTensorType::new(&[], r#type, None).into(),
+                    RankedTensorType::new(&[], r#type, None).unwrap().into(),
                     &[IntegerAttribute::new(r#type, 42).into()],
                 )
                 .unwrap()
@@ -502,7 +511,7 @@ mod tests {
         compile_operation("load", &context, |block| {
             let memref = block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::index(&context), &[], None, None),
+                MemRefType::new(Type::index(&context), &[], None, None).unwrap(),
                 &[],
                 &[],
                 None,
@@ -520,7 +529,8 @@ mod tests {
         compile_operation("load_with_index", &context, |block| {
             let memref = block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::index(&context), &[1], None, None),
+                MemRefType::new(Type::index(&context), &[Dimension::Static(1)], None, None)
+                    .unwrap(),
                 &[],
                 &[],
                 None,
@@ -549,7 +559,8 @@ mod tests {
         compile_operation("rank", &context, |block| {
             let memref = block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::index(&context), &[1], None, None),
+                MemRefType::new(Type::index(&context), &[Dimension::Static(1)], None, None)
+                    .unwrap(),
                 &[],
                 &[],
                 None,
@@ -567,7 +578,7 @@ mod tests {
         compile_operation("store", &context, |block| {
             let memref = block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::index(&context), &[], None, None),
+                MemRefType::new(Type::index(&context), &[], None, None).unwrap(),
                 &[],
                 &[],
                 None,
@@ -597,7 +608,8 @@ mod tests {
         compile_operation("store_with_index", &context, |block| {
             let memref = block.append_operation(alloca(
                 &context,
-                MemRefType::new(Type::index(&context), &[1], None, None),
+                MemRefType::new(Type::index(&context), &[Dimension::Static(1)], None, None)
+                    .unwrap(),
                 &[],
                 &[],
                 None,
@@ -11,10 +11,32 @@ pub enum Error {
     AttributeExpected(&'static str, String),
     AttributeNotFound(String),
     BlockArgumentExpected(String),
+    ConstraintExpected,
+    ContextMismatch(String),
+    ElementCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
     ElementExpected {
         r#type: &'static str,
         value: String,
     },
+    InvalidDimension(u64),
+    InvalidBitWidth {
+        name: &'static str,
+        width: u32,
+    },
+    InvalidPassOption {
+        pass: String,
+        option: String,
+        message: String,
+    },
+    InvalidPermutation(Vec<usize>),
+    InvalidQuantizationParameter {
+        name: &'static str,
+        value: String,
+    },
+    InvalidRawData(String),
     InvokeFunction,
     OperationBuild,
     OperandNotFound(&'static str),
@@ -25,11 +47,13 @@ pub enum Error {
         index: usize,
     },
     ParsePassPipeline(String),
+    PrintIr(String),
     ResultNotFound(&'static str),
     RunPass,
     TypeExpected(&'static str, String),
     UnknownDiagnosticSeverity(u32),
     Utf8(Utf8Error),
+    WriteReproducer(String),
 }
 
 impl Display for Error {
@@ -44,9 +68,41 @@ impl Display for Error {
             Self::BlockArgumentExpected(value) => {
                 write!(formatter, "block argument expected: {value}")
             }
+            Self::ConstraintExpected => write!(formatter, "at least one constraint expected"),
+            Self::ContextMismatch(value) => {
+                write!(formatter, "context mismatch: {value}")
+            }
+            Self::ElementCountMismatch { expected, actual } => {
+                write!(formatter, "{expected} elements expected but got {actual}")
+            }
             Self::ElementExpected { r#type, value } => {
                 write!(formatter, "element of {type} type expected: {value}")
             }
+            Self::InvalidDimension(size) => {
+                write!(formatter, "invalid dimension size: {size}")
+            }
+            Self::InvalidBitWidth { name, width } => {
+                write!(formatter, "invalid {name} bit width: {width}")
+            }
+            Self::InvalidPassOption {
+                pass,
+                option,
+                message,
+            } => {
+                write!(
+                    formatter,
+                    "invalid option {option} of pass {pass}:\n{message}"
+                )
+            }
+            Self::InvalidPermutation(permutation) => {
+                write!(formatter, "invalid permutation: {permutation:?}")
+            }
+            Self::InvalidQuantizationParameter { name, value } => {
+                write!(formatter, "invalid quantization {name}: {value}")
+            }
+            Self::InvalidRawData(r#type) => {
+                write!(formatter, "invalid raw data for type: {type}")
+            }
             Self::InvokeFunction => write!(formatter, "failed to invoke JIT-compiled function"),
             Self::OperationBuild => {
                 write!(formatter, "operation build failed")
@@ -60,6 +116,9 @@ impl Display for Error {
             Self::ParsePassPipeline(message) => {
                 write!(formatter, "failed to parse pass pipeline:\n{}", message)
             }
+            Self::PrintIr(message) => {
+                write!(formatter, "failed to print IR: {message}")
+            }
             Self::PositionOutOfBounds { name, value, index } => {
                 write!(formatter, "{name} position {index} out of bounds: {value}")
             }
@@ -76,6 +135,9 @@ impl Display for Error {
             Self::Utf8(error) => {
                 write!(formatter, "{}", error)
             }
+            Self::WriteReproducer(message) => {
+                write!(formatter, "failed to write reproducer: {message}")
+            }
         }
     }
 }
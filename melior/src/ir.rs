@@ -1,9 +1,11 @@
 //! IR objects and builders.
 
+mod affine_expr;
 mod affine_map;
 pub mod attribute;
 pub mod block;
 mod identifier;
+mod integer_set;
 mod location;
 mod module;
 pub mod operation;
@@ -12,14 +14,16 @@ pub mod r#type;
 mod value;
 
 pub use self::{
+    affine_expr::{AffineExpr, AffineExprKind},
     affine_map::AffineMap,
     attribute::{Attribute, AttributeLike},
     block::{Block, BlockRef},
     identifier::Identifier,
+    integer_set::{IntegerSet, IntegerSetConstraint},
     location::Location,
     module::Module,
     operation::{Operation, OperationRef},
-    r#type::{ShapedTypeLike, Type, TypeLike},
+    r#type::{QuantizedTypeLike, ShapedTypeLike, Type, TypeLike},
     region::{Region, RegionRef},
     value::{Value, ValueLike},
 };
@@ -0,0 +1,419 @@
+use super::AffineMap;
+use crate::{
+    context::{Context, ContextRef},
+    utility::print_callback,
+};
+use mlir_sys::{
+    mlirAffineAddExprGet, mlirAffineBinaryOpExprGetLHS, mlirAffineBinaryOpExprGetRHS,
+    mlirAffineCeilDivExprGet, mlirAffineConstantExprGet, mlirAffineConstantExprGetValue,
+    mlirAffineDimExprGet, mlirAffineDimExprGetPosition, mlirAffineExprCompose, mlirAffineExprDump,
+    mlirAffineExprEqual, mlirAffineExprGetContext, mlirAffineExprGetLargestKnownDivisor,
+    mlirAffineExprIsAAdd, mlirAffineExprIsACeilDiv, mlirAffineExprIsAConstant,
+    mlirAffineExprIsADim, mlirAffineExprIsAFloorDiv, mlirAffineExprIsAMod, mlirAffineExprIsAMul,
+    mlirAffineExprIsASymbol, mlirAffineExprIsFunctionOfDim, mlirAffineExprIsMultipleOf,
+    mlirAffineExprIsPureAffine, mlirAffineExprIsSymbolicOrConstant, mlirAffineExprPrint,
+    mlirAffineFloorDivExprGet, mlirAffineModExprGet, mlirAffineMulExprGet, mlirAffineSymbolExprGet,
+    mlirAffineSymbolExprGetPosition, MlirAffineExpr,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Rem, Sub},
+};
+
+/// An affine expression.
+#[derive(Clone, Copy)]
+pub struct AffineExpr<'c> {
+    raw: MlirAffineExpr,
+    _context: PhantomData<&'c Context>,
+}
+
+impl<'c> AffineExpr<'c> {
+    /// Creates a dimension expression.
+    pub fn dimension(context: &'c Context, position: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineDimExprGet(context.to_raw(), position as isize)) }
+    }
+
+    /// Creates a symbol expression.
+    pub fn symbol(context: &'c Context, position: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineSymbolExprGet(context.to_raw(), position as isize)) }
+    }
+
+    /// Creates a constant expression.
+    pub fn constant(context: &'c Context, value: i64) -> Self {
+        unsafe { Self::from_raw(mlirAffineConstantExprGet(context.to_raw(), value)) }
+    }
+
+    /// Creates a floor division expression.
+    pub fn floor_div(self, rhs: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineFloorDivExprGet(self.raw, rhs.raw)) }
+    }
+
+    /// Creates a ceiling division expression.
+    pub fn ceil_div(self, rhs: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineCeilDivExprGet(self.raw, rhs.raw)) }
+    }
+
+    /// Returns a context.
+    pub fn context(&self) -> ContextRef<'c> {
+        unsafe { ContextRef::from_raw(mlirAffineExprGetContext(self.raw)) }
+    }
+
+    /// Returns a kind.
+    pub fn kind(&self) -> AffineExprKind<'c> {
+        unsafe {
+            if mlirAffineExprIsADim(self.raw) {
+                AffineExprKind::Dimension(mlirAffineDimExprGetPosition(self.raw) as usize)
+            } else if mlirAffineExprIsASymbol(self.raw) {
+                AffineExprKind::Symbol(mlirAffineSymbolExprGetPosition(self.raw) as usize)
+            } else if mlirAffineExprIsAConstant(self.raw) {
+                AffineExprKind::Constant(mlirAffineConstantExprGetValue(self.raw))
+            } else {
+                let lhs = Self::from_raw(mlirAffineBinaryOpExprGetLHS(self.raw));
+                let rhs = Self::from_raw(mlirAffineBinaryOpExprGetRHS(self.raw));
+
+                if mlirAffineExprIsAAdd(self.raw) {
+                    AffineExprKind::Add(lhs, rhs)
+                } else if mlirAffineExprIsAMul(self.raw) {
+                    AffineExprKind::Mul(lhs, rhs)
+                } else if mlirAffineExprIsAMod(self.raw) {
+                    AffineExprKind::Mod(lhs, rhs)
+                } else if mlirAffineExprIsAFloorDiv(self.raw) {
+                    AffineExprKind::FloorDiv(lhs, rhs)
+                } else if mlirAffineExprIsACeilDiv(self.raw) {
+                    AffineExprKind::CeilDiv(lhs, rhs)
+                } else {
+                    unreachable!("unknown affine expression kind")
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if an expression consists of only symbols and constants.
+    pub fn is_symbolic_or_constant(&self) -> bool {
+        unsafe { mlirAffineExprIsSymbolicOrConstant(self.raw) }
+    }
+
+    /// Returns `true` if an expression is pure affine.
+    pub fn is_pure_affine(&self) -> bool {
+        unsafe { mlirAffineExprIsPureAffine(self.raw) }
+    }
+
+    /// Returns the largest known divisor.
+    pub fn largest_known_divisor(&self) -> i64 {
+        unsafe { mlirAffineExprGetLargestKnownDivisor(self.raw) }
+    }
+
+    /// Returns `true` if an expression is a multiple of a factor.
+    pub fn is_multiple_of(&self, factor: i64) -> bool {
+        unsafe { mlirAffineExprIsMultipleOf(self.raw, factor) }
+    }
+
+    /// Returns `true` if an expression involves a dimension.
+    pub fn is_function_of_dimension(&self, position: usize) -> bool {
+        unsafe { mlirAffineExprIsFunctionOfDim(self.raw, position as isize) }
+    }
+
+    /// Composes an expression with an affine map.
+    ///
+    /// Dimensions in the expression are replaced with results of the map.
+    pub fn compose(&self, map: AffineMap<'c>) -> Self {
+        unsafe { Self::from_raw(mlirAffineExprCompose(self.raw, map.to_raw())) }
+    }
+
+    /// Dumps an affine expression.
+    pub fn dump(&self) {
+        unsafe { mlirAffineExprDump(self.raw) }
+    }
+
+    /// Creates an affine expression from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirAffineExpr) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+        }
+    }
+
+    /// Converts an affine expression into a raw object.
+    pub fn to_raw(self) -> MlirAffineExpr {
+        self.raw
+    }
+
+    fn constant_like(&self, value: i64) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineConstantExprGet(
+                mlirAffineExprGetContext(self.raw),
+                value,
+            ))
+        }
+    }
+}
+
+/// A kind of an affine expression.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AffineExprKind<'c> {
+    /// A dimension expression.
+    Dimension(usize),
+    /// A symbol expression.
+    Symbol(usize),
+    /// A constant expression.
+    Constant(i64),
+    /// An addition expression.
+    Add(AffineExpr<'c>, AffineExpr<'c>),
+    /// A multiplication expression.
+    Mul(AffineExpr<'c>, AffineExpr<'c>),
+    /// A modulo expression.
+    Mod(AffineExpr<'c>, AffineExpr<'c>),
+    /// A floor division expression.
+    FloorDiv(AffineExpr<'c>, AffineExpr<'c>),
+    /// A ceiling division expression.
+    CeilDiv(AffineExpr<'c>, AffineExpr<'c>),
+}
+
+impl<'c> Add for AffineExpr<'c> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineAddExprGet(self.raw, rhs.raw)) }
+    }
+}
+
+impl<'c> Add<i64> for AffineExpr<'c> {
+    type Output = Self;
+
+    fn add(self, rhs: i64) -> Self {
+        self + self.constant_like(rhs)
+    }
+}
+
+impl<'c> Sub for AffineExpr<'c> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<'c> Sub<i64> for AffineExpr<'c> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is `i64::MIN`, whose negation
+    /// overflows.
+    fn sub(self, rhs: i64) -> Self {
+        self.add(
+            rhs.checked_neg()
+                .expect("affine constant to subtract must be negatable"),
+        )
+    }
+}
+
+impl<'c> Mul for AffineExpr<'c> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineMulExprGet(self.raw, rhs.raw)) }
+    }
+}
+
+impl<'c> Mul<i64> for AffineExpr<'c> {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        self * self.constant_like(rhs)
+    }
+}
+
+impl<'c> Rem for AffineExpr<'c> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        unsafe { Self::from_raw(mlirAffineModExprGet(self.raw, rhs.raw)) }
+    }
+}
+
+impl<'c> Rem<i64> for AffineExpr<'c> {
+    type Output = Self;
+
+    fn rem(self, rhs: i64) -> Self {
+        self % self.constant_like(rhs)
+    }
+}
+
+impl<'c> Neg for AffineExpr<'c> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function will panic if an expression is a constant of `i64::MIN`,
+    /// whose negation overflows.
+    fn neg(self) -> Self {
+        // MLIR folds constants with signed integer arithmetic, which must not
+        // overflow.
+        if let AffineExprKind::Constant(value) = self.kind() {
+            self.constant_like(
+                value
+                    .checked_neg()
+                    .expect("affine constant to negate must be negatable"),
+            )
+        } else {
+            self * -1
+        }
+    }
+}
+
+impl<'c> PartialEq for AffineExpr<'c> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirAffineExprEqual(self.raw, other.raw) }
+    }
+}
+
+impl<'c> Eq for AffineExpr<'c> {}
+
+impl<'c> Display for AffineExpr<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let mut data = (formatter, Ok(()));
+
+        unsafe {
+            mlirAffineExprPrint(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+    }
+}
+
+impl<'c> Debug for AffineExpr<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn dimension() {
+        let context = Context::new();
+        let expression = AffineExpr::dimension(&context, 1);
+
+        assert_eq!(expression.to_string(), "d1");
+        assert_eq!(expression.kind(), AffineExprKind::Dimension(1));
+    }
+
+    #[test]
+    fn symbol() {
+        let context = Context::new();
+        let expression = AffineExpr::symbol(&context, 2);
+
+        assert_eq!(expression.to_string(), "s2");
+        assert_eq!(expression.kind(), AffineExprKind::Symbol(2));
+    }
+
+    #[test]
+    fn constant() {
+        let context = Context::new();
+        let expression = AffineExpr::constant(&context, 42);
+
+        assert_eq!(expression.to_string(), "42");
+        assert_eq!(expression.kind(), AffineExprKind::Constant(42));
+    }
+
+    #[test]
+    #[should_panic]
+    fn subtract_minimum_integer() {
+        let context = Context::new();
+
+        let _ = AffineExpr::dimension(&context, 0) - i64::MIN;
+    }
+
+    #[test]
+    #[should_panic]
+    fn negate_minimum_constant() {
+        let context = Context::new();
+
+        let _ = -AffineExpr::constant(&context, i64::MIN);
+    }
+
+    #[test]
+    fn binary() {
+        let context = Context::new();
+        let dimension = AffineExpr::dimension(&context, 0);
+        let symbol = AffineExpr::symbol(&context, 0);
+
+        assert_eq!((dimension + symbol).to_string(), "d0 + s0");
+        assert_eq!((dimension - symbol).to_string(), "d0 - s0");
+        assert_eq!((dimension - 4).to_string(), "d0 - 4");
+        assert_eq!((dimension * 4).to_string(), "d0 * 4");
+        assert_eq!((dimension % 4).to_string(), "d0 mod 4");
+        assert_eq!(
+            dimension
+                .floor_div(AffineExpr::constant(&context, 4))
+                .to_string(),
+            "d0 floordiv 4"
+        );
+        assert_eq!(
+            dimension
+                .ceil_div(AffineExpr::constant(&context, 4))
+                .to_string(),
+            "d0 ceildiv 4"
+        );
+        assert_eq!(
+            (dimension + symbol).kind(),
+            AffineExprKind::Add(dimension, symbol)
+        );
+    }
+
+    #[test]
+    fn is_pure_affine() {
+        let context = Context::new();
+        let dimension = AffineExpr::dimension(&context, 0);
+
+        assert!((dimension * 2).is_pure_affine());
+        assert!(!(dimension * dimension).is_pure_affine());
+    }
+
+    #[test]
+    fn is_multiple_of() {
+        let context = Context::new();
+        let expression = AffineExpr::dimension(&context, 0) * 6;
+
+        assert_eq!(expression.largest_known_divisor(), 6);
+        assert!(expression.is_multiple_of(3));
+        assert!(!expression.is_multiple_of(4));
+    }
+
+    #[test]
+    fn is_function_of_dimension() {
+        let context = Context::new();
+        let expression = AffineExpr::dimension(&context, 1) + AffineExpr::symbol(&context, 0);
+
+        assert!(!expression.is_function_of_dimension(0));
+        assert!(expression.is_function_of_dimension(1));
+        assert!(!expression.is_symbolic_or_constant());
+    }
+
+    #[test]
+    fn compose() {
+        let context = Context::new();
+        let map = AffineMap::new(&context, 1, 0, &[AffineExpr::dimension(&context, 0) * 2]);
+
+        assert_eq!(
+            (AffineExpr::dimension(&context, 0) + 1)
+                .compose(map)
+                .to_string(),
+            "d0 * 2 + 1"
+        );
+    }
+}
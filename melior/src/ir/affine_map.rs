@@ -1,9 +1,19 @@
+use super::AffineExpr;
 use crate::{
     context::{Context, ContextRef},
     utility::print_callback,
+    Error,
 };
 use mlir_sys::{
-    mlirAffineMapDump, mlirAffineMapEqual, mlirAffineMapGetContext, mlirAffineMapPrint,
+    mlirAffineMapConstantGet, mlirAffineMapDump, mlirAffineMapEmptyGet, mlirAffineMapEqual,
+    mlirAffineMapGet, mlirAffineMapGetContext, mlirAffineMapGetMajorSubMap,
+    mlirAffineMapGetMinorSubMap, mlirAffineMapGetNumDims, mlirAffineMapGetNumInputs,
+    mlirAffineMapGetNumResults, mlirAffineMapGetNumSymbols, mlirAffineMapGetResult,
+    mlirAffineMapGetSingleConstantResult, mlirAffineMapGetSubMap, mlirAffineMapIsEmpty,
+    mlirAffineMapIsIdentity, mlirAffineMapIsMinorIdentity, mlirAffineMapIsPermutation,
+    mlirAffineMapIsProjectedPermutation, mlirAffineMapIsSingleConstant,
+    mlirAffineMapMinorIdentityGet, mlirAffineMapMultiDimIdentityGet, mlirAffineMapPermutationGet,
+    mlirAffineMapPrint, mlirAffineMapReplace, mlirAffineMapZeroResultGet, mlirAffineSymbolExprGet,
     MlirAffineMap,
 };
 use std::{
@@ -20,11 +30,275 @@ pub struct AffineMap<'c> {
 }
 
 impl<'c> AffineMap<'c> {
+    /// Creates an affine map.
+    pub fn new(
+        context: &'c Context,
+        dimension_count: usize,
+        symbol_count: usize,
+        results: &[AffineExpr<'c>],
+    ) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+                results.len() as isize,
+                results.as_ptr() as *const _ as *mut _,
+            ))
+        }
+    }
+
+    /// Creates an empty affine map.
+    pub fn empty(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapEmptyGet(context.to_raw())) }
+    }
+
+    /// Creates an affine map with no results.
+    pub fn zero_result(context: &'c Context, dimension_count: usize, symbol_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapZeroResultGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        }
+    }
+
+    /// Creates a single constant result affine map.
+    pub fn constant(context: &'c Context, value: i64) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapConstantGet(context.to_raw(), value)) }
+    }
+
+    /// Creates an identity affine map.
+    pub fn identity(context: &'c Context, dimension_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapMultiDimIdentityGet(
+                context.to_raw(),
+                dimension_count as isize,
+            ))
+        }
+    }
+
+    /// Creates an identity affine map on the most minor dimensions.
+    pub fn minor_identity(
+        context: &'c Context,
+        dimension_count: usize,
+        result_count: usize,
+    ) -> Result<Self, Error> {
+        if result_count > dimension_count {
+            return Err(Error::PositionOutOfBounds {
+                name: "minor identity result",
+                value: format!("{dimension_count} dimensions"),
+                index: result_count,
+            });
+        }
+
+        Ok(unsafe {
+            Self::from_raw(mlirAffineMapMinorIdentityGet(
+                context.to_raw(),
+                dimension_count as isize,
+                result_count as isize,
+            ))
+        })
+    }
+
+    /// Creates a permutation affine map.
+    pub fn permutation(context: &'c Context, permutation: &[usize]) -> Result<Self, Error> {
+        let mut sorted = permutation.to_vec();
+        sorted.sort_unstable();
+
+        if permutation.is_empty() || sorted.iter().copied().ne(0..permutation.len()) {
+            return Err(Error::InvalidPermutation(permutation.to_vec()));
+        }
+
+        let mut permutation = permutation
+            .iter()
+            .map(|&index| index as u32)
+            .collect::<Vec<_>>();
+
+        Ok(unsafe {
+            Self::from_raw(mlirAffineMapPermutationGet(
+                context.to_raw(),
+                permutation.len() as isize,
+                permutation.as_mut_ptr(),
+            ))
+        })
+    }
+
     /// Returns a context.
     pub fn context(&self) -> ContextRef<'c> {
         unsafe { ContextRef::from_raw(mlirAffineMapGetContext(self.raw)) }
     }
 
+    /// Returns a number of dimensions.
+    pub fn dimension_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumDims(self.raw) as usize }
+    }
+
+    /// Returns a number of symbols.
+    pub fn symbol_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumSymbols(self.raw) as usize }
+    }
+
+    /// Returns a number of inputs, which are dimensions and symbols.
+    pub fn input_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumInputs(self.raw) as usize }
+    }
+
+    /// Returns a number of results.
+    pub fn result_count(&self) -> usize {
+        unsafe { mlirAffineMapGetNumResults(self.raw) as usize }
+    }
+
+    /// Returns a result.
+    pub fn result(&self, index: usize) -> Result<AffineExpr<'c>, Error> {
+        if index < self.result_count() {
+            Ok(unsafe { AffineExpr::from_raw(mlirAffineMapGetResult(self.raw, index as isize)) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "affine map result",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns results.
+    pub fn results(&self) -> impl Iterator<Item = AffineExpr<'c>> + '_ {
+        (0..self.result_count()).map(|index| unsafe {
+            AffineExpr::from_raw(mlirAffineMapGetResult(self.raw, index as isize))
+        })
+    }
+
+    /// Returns a constant result if an affine map has a single constant result.
+    pub fn single_constant_result(&self) -> Option<i64> {
+        unsafe {
+            mlirAffineMapIsSingleConstant(self.raw)
+                .then(|| mlirAffineMapGetSingleConstantResult(self.raw))
+        }
+    }
+
+    /// Returns `true` if an affine map is empty.
+    pub fn is_empty(&self) -> bool {
+        unsafe { mlirAffineMapIsEmpty(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is an identity.
+    pub fn is_identity(&self) -> bool {
+        unsafe { mlirAffineMapIsIdentity(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is a minor identity.
+    pub fn is_minor_identity(&self) -> bool {
+        unsafe { mlirAffineMapIsMinorIdentity(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is a permutation.
+    pub fn is_permutation(&self) -> bool {
+        unsafe { mlirAffineMapIsPermutation(self.raw) }
+    }
+
+    /// Returns `true` if an affine map is a projected permutation.
+    pub fn is_projected_permutation(&self) -> bool {
+        unsafe { mlirAffineMapIsProjectedPermutation(self.raw) }
+    }
+
+    /// Returns a sub map of results at given positions.
+    pub fn sub_map(&self, positions: &[usize]) -> Result<Self, Error> {
+        if let Some(&index) = positions
+            .iter()
+            .find(|&&index| index >= self.result_count())
+        {
+            return Err(Error::PositionOutOfBounds {
+                name: "affine map result",
+                value: self.to_string(),
+                index,
+            });
+        }
+
+        let mut positions = positions
+            .iter()
+            .map(|&index| index as isize)
+            .collect::<Vec<_>>();
+
+        Ok(unsafe {
+            Self::from_raw(mlirAffineMapGetSubMap(
+                self.raw,
+                positions.len() as isize,
+                positions.as_mut_ptr(),
+            ))
+        })
+    }
+
+    /// Returns a sub map of the most major results.
+    pub fn major_sub_map(&self, result_count: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapGetMajorSubMap(self.raw, result_count as isize)) }
+    }
+
+    /// Returns a sub map of the most minor results.
+    pub fn minor_sub_map(&self, result_count: usize) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapGetMinorSubMap(self.raw, result_count as isize)) }
+    }
+
+    /// Replaces an expression in results.
+    pub fn replace(
+        &self,
+        expression: AffineExpr<'c>,
+        replacement: AffineExpr<'c>,
+        dimension_count: usize,
+        symbol_count: usize,
+    ) -> Self {
+        unsafe {
+            Self::from_raw(mlirAffineMapReplace(
+                self.raw,
+                expression.to_raw(),
+                replacement.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        }
+    }
+
+    /// Composes an affine map with another one.
+    ///
+    /// The result is `self(other(...))`. It takes dimensions of the other map
+    /// and symbols of both maps where the ones of the other map come first.
+    pub fn compose(&self, other: Self) -> Self {
+        let dimension_count = other.dimension_count();
+        let symbol_count = other.symbol_count() + self.symbol_count();
+        let context = unsafe { mlirAffineMapGetContext(self.raw) };
+
+        // Shift symbols of this map in descending order so that the replaced
+        // ones never collide with the ones not replaced yet.
+        let map = (0..self.symbol_count())
+            .rev()
+            .fold(*self, |map, index| unsafe {
+                map.replace(
+                    AffineExpr::from_raw(mlirAffineSymbolExprGet(context, index as isize)),
+                    AffineExpr::from_raw(mlirAffineSymbolExprGet(
+                        context,
+                        (other.symbol_count() + index) as isize,
+                    )),
+                    self.dimension_count(),
+                    symbol_count,
+                )
+            });
+        let mut results = map
+            .results()
+            .map(|result| result.compose(other).to_raw())
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirAffineMapGet(
+                context,
+                dimension_count as isize,
+                symbol_count as isize,
+                results.len() as isize,
+                results.as_mut_ptr(),
+            ))
+        }
+    }
+
     /// Dumps an affine map.
     pub fn dump(&self) {
         unsafe { mlirAffineMapDump(self.raw) }
@@ -41,6 +315,11 @@ impl<'c> AffineMap<'c> {
             _context: Default::default(),
         }
     }
+
+    /// Converts an affine map into a raw object.
+    pub fn to_raw(self) -> MlirAffineMap {
+        self.raw
+    }
 }
 
 impl<'c> PartialEq for AffineMap<'c> {
@@ -72,3 +351,158 @@ impl<'c> Debug for AffineMap<'c> {
         Display::fmt(self, formatter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+        let map = AffineMap::new(
+            &context,
+            2,
+            1,
+            &[
+                AffineExpr::dimension(&context, 1),
+                AffineExpr::dimension(&context, 0) + AffineExpr::symbol(&context, 0),
+            ],
+        );
+
+        assert_eq!(map.to_string(), "(d0, d1)[s0] -> (d1, d0 + s0)");
+        assert_eq!(map.dimension_count(), 2);
+        assert_eq!(map.symbol_count(), 1);
+        assert_eq!(map.input_count(), 3);
+        assert_eq!(map.result_count(), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let context = Context::new();
+
+        assert!(AffineMap::empty(&context).is_empty());
+        assert_eq!(AffineMap::empty(&context).to_string(), "() -> ()");
+        assert_eq!(
+            AffineMap::zero_result(&context, 2, 1).to_string(),
+            "(d0, d1)[s0] -> ()"
+        );
+    }
+
+    #[test]
+    fn constant() {
+        let context = Context::new();
+        let map = AffineMap::constant(&context, 42);
+
+        assert_eq!(map.to_string(), "() -> (42)");
+        assert_eq!(map.single_constant_result(), Some(42));
+        assert_eq!(
+            AffineMap::identity(&context, 1).single_constant_result(),
+            None
+        );
+    }
+
+    #[test]
+    fn identity() {
+        let context = Context::new();
+        let map = AffineMap::identity(&context, 2);
+
+        assert_eq!(map.to_string(), "(d0, d1) -> (d0, d1)");
+        assert!(map.is_identity());
+        assert!(map.is_permutation());
+    }
+
+    #[test]
+    fn minor_identity() {
+        let context = Context::new();
+        let map = AffineMap::minor_identity(&context, 3, 2).unwrap();
+
+        assert_eq!(map.to_string(), "(d0, d1, d2) -> (d1, d2)");
+        assert!(map.is_minor_identity());
+        assert!(map.is_projected_permutation());
+        assert!(!map.is_identity());
+        assert!(matches!(
+            AffineMap::minor_identity(&context, 1, 2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn permutation() {
+        let context = Context::new();
+        let map = AffineMap::permutation(&context, &[1, 2, 0]).unwrap();
+
+        assert_eq!(map.to_string(), "(d0, d1, d2) -> (d1, d2, d0)");
+        assert!(map.is_permutation());
+        assert!(!map.is_identity());
+        assert!(matches!(
+            AffineMap::permutation(&context, &[0, 0]),
+            Err(Error::InvalidPermutation(_))
+        ));
+        assert!(matches!(
+            AffineMap::permutation(&context, &[]),
+            Err(Error::InvalidPermutation(_))
+        ));
+    }
+
+    #[test]
+    fn result() {
+        let context = Context::new();
+        let map = AffineMap::permutation(&context, &[1, 0]).unwrap();
+
+        assert_eq!(map.result(0).unwrap(), AffineExpr::dimension(&context, 1));
+        assert_eq!(
+            map.results().collect::<Vec<_>>(),
+            [
+                AffineExpr::dimension(&context, 1),
+                AffineExpr::dimension(&context, 0)
+            ]
+        );
+        assert!(matches!(
+            map.result(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn sub_map() {
+        let context = Context::new();
+        let map = AffineMap::identity(&context, 3);
+
+        assert_eq!(
+            map.sub_map(&[0, 2]).unwrap().to_string(),
+            "(d0, d1, d2) -> (d0, d2)"
+        );
+        assert_eq!(map.major_sub_map(1).to_string(), "(d0, d1, d2) -> (d0)");
+        assert_eq!(map.minor_sub_map(1).to_string(), "(d0, d1, d2) -> (d2)");
+        assert!(matches!(
+            map.sub_map(&[3]),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn compose() {
+        let context = Context::new();
+        let map = AffineMap::new(
+            &context,
+            2,
+            1,
+            &[AffineExpr::dimension(&context, 0) + AffineExpr::symbol(&context, 0)],
+        );
+        let other = AffineMap::new(
+            &context,
+            1,
+            1,
+            &[
+                AffineExpr::dimension(&context, 0) * 2,
+                AffineExpr::symbol(&context, 0),
+            ],
+        );
+
+        assert_eq!(
+            map.compose(other).to_string(),
+            "(d0)[s0, s1] -> (d0 * 2 + s1)"
+        );
+    }
+}
@@ -2,22 +2,63 @@
 
 #[macro_use]
 mod r#macro;
+mod affine_map;
 mod array;
 mod attribute_like;
+mod bool;
+mod dense_bool_array;
 mod dense_elements;
+mod dense_f32_array;
+mod dense_f64_array;
+mod dense_i16_array;
 mod dense_i32_array;
 mod dense_i64_array;
+mod dense_i8_array;
+mod dense_resource_elements;
+mod dictionary;
 mod flat_symbol_ref;
 mod float;
 mod integer;
+mod integer_set;
+mod kind;
+mod opaque;
+mod sparse_elements;
+mod sparse_tensor_encoding;
+mod strided_layout;
 mod string;
+mod symbol_ref;
 mod r#type;
 
+pub(crate) use self::dense_resource_elements::ResourceBlob;
 pub use self::{
-    array::ArrayAttribute, attribute_like::AttributeLike, dense_elements::DenseElementsAttribute,
-    dense_i32_array::DenseI32ArrayAttribute, dense_i64_array::DenseI64ArrayAttribute,
-    flat_symbol_ref::FlatSymbolRefAttribute, float::FloatAttribute, integer::IntegerAttribute,
-    r#type::TypeAttribute, string::StringAttribute,
+    affine_map::AffineMapAttribute,
+    array::ArrayAttribute,
+    attribute_like::AttributeLike,
+    bool::BoolAttribute,
+    dense_bool_array::DenseBoolArrayAttribute,
+    dense_elements::{DenseElement, DenseElementsAttribute},
+    dense_f32_array::DenseF32ArrayAttribute,
+    dense_f64_array::DenseF64ArrayAttribute,
+    dense_i16_array::DenseI16ArrayAttribute,
+    dense_i32_array::DenseI32ArrayAttribute,
+    dense_i64_array::DenseI64ArrayAttribute,
+    dense_i8_array::DenseI8ArrayAttribute,
+    dense_resource_elements::{DenseResourceElement, DenseResourceElementsAttribute},
+    dictionary::DictionaryAttribute,
+    flat_symbol_ref::FlatSymbolRefAttribute,
+    float::FloatAttribute,
+    integer::IntegerAttribute,
+    integer_set::IntegerSetAttribute,
+    kind::AttributeKind,
+    opaque::OpaqueAttribute,
+    r#type::TypeAttribute,
+    sparse_elements::SparseElementsAttribute,
+    sparse_tensor_encoding::{
+        SparseTensorEncodingAttribute, SparseTensorLevelFormat, SparseTensorLevelType,
+    },
+    strided_layout::StridedLayoutAttribute,
+    string::StringAttribute,
+    symbol_ref::SymbolRefAttribute,
 };
 use crate::{context::Context, string_ref::StringRef, utility::print_callback};
 use mlir_sys::{
@@ -54,6 +95,11 @@ impl<'c> Attribute<'c> {
         unsafe { Self::from_raw(mlirUnitAttrGet(context.to_raw())) }
     }
 
+    /// Returns a kind.
+    pub fn kind(&self) -> AttributeKind<'c> {
+        AttributeKind::from(*self)
+    }
+
     pub(crate) unsafe fn null() -> Self {
         unsafe { Self::from_raw(mlirAttributeGetNull()) }
     }
@@ -122,14 +168,29 @@ impl<'c> Debug for Attribute<'c> {
 
 from_subtypes!(
     Attribute,
+    AffineMapAttribute,
     ArrayAttribute,
+    BoolAttribute,
+    DenseBoolArrayAttribute,
     DenseElementsAttribute,
+    DenseF32ArrayAttribute,
+    DenseF64ArrayAttribute,
+    DenseI16ArrayAttribute,
     DenseI32ArrayAttribute,
     DenseI64ArrayAttribute,
+    DenseI8ArrayAttribute,
+    DenseResourceElementsAttribute,
+    DictionaryAttribute,
     FlatSymbolRefAttribute,
     FloatAttribute,
     IntegerAttribute,
+    IntegerSetAttribute,
+    OpaqueAttribute,
+    SparseElementsAttribute,
+    SparseTensorEncodingAttribute,
+    StridedLayoutAttribute,
     StringAttribute,
+    SymbolRefAttribute,
     TypeAttribute,
 );
 
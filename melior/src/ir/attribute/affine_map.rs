@@ -0,0 +1,61 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::AffineMap, Error};
+use mlir_sys::{mlirAffineMapAttrGet, mlirAffineMapAttrGetValue, MlirAttribute};
+
+/// An affine map attribute.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct AffineMapAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> AffineMapAttribute<'c> {
+    /// Creates an affine map attribute.
+    pub fn new(map: AffineMap<'c>) -> Self {
+        unsafe { Self::from_raw(mlirAffineMapAttrGet(map.to_raw())) }
+    }
+
+    /// Returns an affine map value.
+    pub fn value(&self) -> AffineMap<'c> {
+        unsafe { AffineMap::from_raw(mlirAffineMapAttrGetValue(self.to_raw())) }
+    }
+}
+
+attribute_traits!(AffineMapAttribute, is_affine_map, "affine map");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(AffineMapAttribute::new(AffineMap::identity(&context, 2))),
+            Attribute::parse(&context, "affine_map<(d0, d1) -> (d0, d1)>").unwrap()
+        );
+    }
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let map = AffineMap::permutation(&context, &[1, 0]).unwrap();
+
+        assert_eq!(AffineMapAttribute::new(map).value(), map);
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(AffineMapAttribute::try_from(
+            Attribute::parse(&context, "affine_map<(d0) -> (d0)>").unwrap()
+        )
+        .is_ok());
+        assert!(matches!(
+            AffineMapAttribute::try_from(Attribute::parse(&context, "[42]").unwrap()),
+            Err(Error::AttributeExpected("affine map", _))
+        ));
+    }
+}
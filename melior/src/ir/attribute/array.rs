@@ -5,7 +5,7 @@ use mlir_sys::{
 };
 
 /// An array attribute.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 pub struct ArrayAttribute<'c> {
     attribute: Attribute<'c>,
 }
@@ -51,7 +51,7 @@ impl<'c> ArrayAttribute<'c> {
     }
 }
 
-attribute_traits!(ArrayAttribute, is_dense_i64_array, "dense i64 array");
+attribute_traits!(ArrayAttribute, is_array, "array");
 
 #[cfg(test)]
 mod tests {
@@ -93,4 +93,15 @@ mod tests {
 
         assert_eq!(attribute.len(), 1);
     }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(ArrayAttribute::try_from(Attribute::parse(&context, "[42]").unwrap()).is_ok());
+        assert!(matches!(
+            ArrayAttribute::try_from(Attribute::parse(&context, "array<i64: 42>").unwrap()),
+            Err(Error::AttributeExpected("array", _))
+        ));
+    }
 }
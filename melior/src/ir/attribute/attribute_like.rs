@@ -46,6 +46,7 @@ pub trait AttributeLike<'c> {
         mlirAttributeIsADenseI32Array,
         mlirAttributeIsADenseI64Array,
         mlirAttributeIsADenseI8Array,
+        mlirAttributeIsADenseResourceElements,
         mlirAttributeIsADenseIntElements,
         mlirAttributeIsADictionary,
         mlirAttributeIsAElements,
@@ -0,0 +1,57 @@
+use super::{Attribute, AttributeLike};
+use crate::{Context, Error};
+use mlir_sys::{mlirBoolAttrGet, mlirBoolAttrGetValue, MlirAttribute};
+
+/// A bool attribute.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct BoolAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> BoolAttribute<'c> {
+    /// Creates a bool attribute.
+    pub fn new(context: &'c Context, value: bool) -> Self {
+        unsafe { Self::from_raw(mlirBoolAttrGet(context.to_raw(), value as i32)) }
+    }
+
+    /// Returns a value.
+    pub fn value(&self) -> bool {
+        unsafe { mlirBoolAttrGetValue(self.to_raw()) }
+    }
+}
+
+attribute_traits!(BoolAttribute, is_bool, "bool");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(BoolAttribute::new(&context, true)),
+            Attribute::parse(&context, "true").unwrap()
+        );
+    }
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+
+        assert!(BoolAttribute::new(&context, true).value());
+        assert!(!BoolAttribute::new(&context, false).value());
+    }
+
+    #[test]
+    fn try_from_integer() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            BoolAttribute::try_from(Attribute::parse(&context, "42").unwrap()),
+            Err(Error::AttributeExpected("bool", _))
+        ));
+    }
+}
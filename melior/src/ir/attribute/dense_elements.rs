@@ -1,15 +1,197 @@
 use super::{Attribute, AttributeLike};
 use crate::{
-    ir::{Type, TypeLike},
+    ir::{r#type::IntegerType, Type, TypeLike},
     Error,
 };
 use mlir_sys::{
-    mlirDenseElementsAttrGet, mlirDenseElementsAttrGetInt32Value,
-    mlirDenseElementsAttrGetInt64Value, mlirElementsAttrGetNumElements, MlirAttribute,
+    mlirAttributeGetType, mlirDenseElementsAttrBoolGet, mlirDenseElementsAttrDoubleGet,
+    mlirDenseElementsAttrFloatGet, mlirDenseElementsAttrGet, mlirDenseElementsAttrGetBoolValue,
+    mlirDenseElementsAttrGetDoubleValue, mlirDenseElementsAttrGetFloatValue,
+    mlirDenseElementsAttrGetInt16Value, mlirDenseElementsAttrGetInt32Value,
+    mlirDenseElementsAttrGetInt64Value, mlirDenseElementsAttrGetInt8Value,
+    mlirDenseElementsAttrGetRawData, mlirDenseElementsAttrGetUInt16Value,
+    mlirDenseElementsAttrGetUInt32Value, mlirDenseElementsAttrGetUInt64Value,
+    mlirDenseElementsAttrGetUInt8Value, mlirDenseElementsAttrInt16Get,
+    mlirDenseElementsAttrInt32Get, mlirDenseElementsAttrInt64Get, mlirDenseElementsAttrInt8Get,
+    mlirDenseElementsAttrIsSplat, mlirDenseElementsAttrRawBufferGet,
+    mlirDenseElementsAttrUInt16Get, mlirDenseElementsAttrUInt32Get, mlirDenseElementsAttrUInt64Get,
+    mlirDenseElementsAttrUInt8Get, mlirElementsAttrGetNumElements, mlirShapedTypeGetDimSize,
+    mlirShapedTypeGetElementType, mlirShapedTypeGetRank, mlirShapedTypeHasStaticShape,
+    MlirAttribute, MlirType,
 };
+use std::slice;
+
+/// An element of dense elements attributes.
+pub trait DenseElement: Copy {
+    /// Returns a name of an element type.
+    fn name() -> &'static str;
+
+    /// Checks if a type is a valid element type.
+    fn is_element_type(r#type: Type) -> bool;
+
+    /// Creates a dense elements attribute as a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw type must be a statically shaped type with a valid element type.
+    unsafe fn create_raw(r#type: MlirType, values: &[Self]) -> MlirAttribute;
+
+    /// Returns an element of a raw dense elements attribute.
+    ///
+    /// # Safety
+    ///
+    /// A raw attribute must have a valid element type and an index must be in
+    /// bounds.
+    unsafe fn element_raw(attribute: MlirAttribute, index: usize) -> Self;
+}
+
+macro_rules! dense_element {
+    ($type:ty, $name:literal, $width:literal, $create:ident, $element:ident) => {
+        impl DenseElement for $type {
+            fn name() -> &'static str {
+                $name
+            }
+
+            fn is_element_type(r#type: Type) -> bool {
+                integer_width(r#type) == Some($width)
+            }
+
+            unsafe fn create_raw(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+                $create(r#type, values.len() as isize, values.as_ptr())
+            }
+
+            unsafe fn element_raw(attribute: MlirAttribute, index: usize) -> Self {
+                $element(attribute, index as isize)
+            }
+        }
+    };
+}
+
+dense_element!(
+    i8,
+    "i8",
+    8,
+    mlirDenseElementsAttrInt8Get,
+    mlirDenseElementsAttrGetInt8Value
+);
+dense_element!(
+    u8,
+    "u8",
+    8,
+    mlirDenseElementsAttrUInt8Get,
+    mlirDenseElementsAttrGetUInt8Value
+);
+dense_element!(
+    i16,
+    "i16",
+    16,
+    mlirDenseElementsAttrInt16Get,
+    mlirDenseElementsAttrGetInt16Value
+);
+dense_element!(
+    u16,
+    "u16",
+    16,
+    mlirDenseElementsAttrUInt16Get,
+    mlirDenseElementsAttrGetUInt16Value
+);
+dense_element!(
+    i32,
+    "i32",
+    32,
+    mlirDenseElementsAttrInt32Get,
+    mlirDenseElementsAttrGetInt32Value
+);
+dense_element!(
+    u32,
+    "u32",
+    32,
+    mlirDenseElementsAttrUInt32Get,
+    mlirDenseElementsAttrGetUInt32Value
+);
+dense_element!(
+    i64,
+    "i64",
+    64,
+    mlirDenseElementsAttrInt64Get,
+    mlirDenseElementsAttrGetInt64Value
+);
+dense_element!(
+    u64,
+    "u64",
+    64,
+    mlirDenseElementsAttrUInt64Get,
+    mlirDenseElementsAttrGetUInt64Value
+);
+
+impl DenseElement for bool {
+    fn name() -> &'static str {
+        "bool"
+    }
+
+    fn is_element_type(r#type: Type) -> bool {
+        integer_width(r#type) == Some(1)
+    }
+
+    unsafe fn create_raw(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+        let values = values.iter().map(|&value| value as i32).collect::<Vec<_>>();
+
+        mlirDenseElementsAttrBoolGet(r#type, values.len() as isize, values.as_ptr())
+    }
+
+    unsafe fn element_raw(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseElementsAttrGetBoolValue(attribute, index as isize)
+    }
+}
+
+impl DenseElement for f32 {
+    fn name() -> &'static str {
+        "f32"
+    }
+
+    fn is_element_type(r#type: Type) -> bool {
+        r#type.is_f32()
+    }
+
+    unsafe fn create_raw(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+        mlirDenseElementsAttrFloatGet(r#type, values.len() as isize, values.as_ptr())
+    }
+
+    unsafe fn element_raw(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseElementsAttrGetFloatValue(attribute, index as isize)
+    }
+}
+
+impl DenseElement for f64 {
+    fn name() -> &'static str {
+        "f64"
+    }
+
+    fn is_element_type(r#type: Type) -> bool {
+        r#type.is_f64()
+    }
+
+    unsafe fn create_raw(r#type: MlirType, values: &[Self]) -> MlirAttribute {
+        mlirDenseElementsAttrDoubleGet(r#type, values.len() as isize, values.as_ptr())
+    }
+
+    unsafe fn element_raw(attribute: MlirAttribute, index: usize) -> Self {
+        mlirDenseElementsAttrGetDoubleValue(attribute, index as isize)
+    }
+}
+
+fn integer_width(r#type: Type) -> Option<u32> {
+    if r#type.is_index() {
+        Some(64)
+    } else {
+        IntegerType::try_from(r#type)
+            .ok()
+            .map(|r#type| r#type.width())
+    }
+}
 
 /// A dense elements attribute.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 pub struct DenseElementsAttribute<'c> {
     attribute: Attribute<'c>,
 }
@@ -30,6 +212,52 @@ impl<'c> DenseElementsAttribute<'c> {
         }
     }
 
+    /// Creates a dense elements attribute from values.
+    ///
+    /// The number of values must be either the number of elements in a type
+    /// or one for a splat.
+    pub fn from_values<T: DenseElement>(r#type: Type<'c>, values: &[T]) -> Result<Self, Error> {
+        let length = static_element_count(r#type)?;
+        let element_type = unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) };
+
+        if !T::is_element_type(element_type) {
+            Err(Error::ElementExpected {
+                r#type: T::name(),
+                value: r#type.to_string(),
+            })
+        } else if values.len() != length && values.len() != 1 {
+            Err(Error::ElementCountMismatch {
+                expected: length,
+                actual: values.len(),
+            })
+        } else {
+            Ok(unsafe { Self::from_raw(T::create_raw(r#type.to_raw(), values)) })
+        }
+    }
+
+    /// Creates a splat dense elements attribute.
+    pub fn splat<T: DenseElement>(r#type: Type<'c>, value: T) -> Result<Self, Error> {
+        Self::from_values(r#type, &[value])
+    }
+
+    /// Creates a dense elements attribute from raw data.
+    ///
+    /// The data must be in the same layout as [`raw_data`](Self::raw_data)
+    /// returns.
+    pub fn from_raw_data(r#type: Type<'c>, data: &[u8]) -> Result<Self, Error> {
+        static_element_count(r#type)?;
+
+        unsafe {
+            Attribute::from_option_raw(mlirDenseElementsAttrRawBufferGet(
+                r#type.to_raw(),
+                data.len(),
+                data.as_ptr() as *const _,
+            ))
+        }
+        .map(|attribute| unsafe { Self::from_raw(attribute.to_raw()) })
+        .ok_or_else(|| Error::InvalidRawData(r#type.to_string()))
+    }
+
     /// Returns a length.
     pub fn len(&self) -> usize {
         (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
@@ -40,6 +268,95 @@ impl<'c> DenseElementsAttribute<'c> {
         self.len() == 0
     }
 
+    /// Checks if all elements are the same.
+    pub fn is_splat(&self) -> bool {
+        unsafe { mlirDenseElementsAttrIsSplat(self.attribute.to_raw()) }
+    }
+
+    /// Returns an element.
+    pub fn element<T: DenseElement>(&self, index: usize) -> Result<T, Error> {
+        if !T::is_element_type(self.element_type()) {
+            Err(Error::ElementExpected {
+                r#type: T::name(),
+                value: self.to_string(),
+            })
+        } else if index < self.len() {
+            Ok(unsafe { T::element_raw(self.attribute.to_raw(), index) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dense element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all elements.
+    pub fn elements<T: DenseElement>(&self) -> Result<impl Iterator<Item = T> + '_, Error> {
+        if T::is_element_type(self.element_type()) {
+            Ok((0..self.len())
+                .map(|index| unsafe { T::element_raw(self.attribute.to_raw(), index) }))
+        } else {
+            Err(Error::ElementExpected {
+                r#type: T::name(),
+                value: self.to_string(),
+            })
+        }
+    }
+
+    /// Returns an f32 element.
+    pub fn f32_element(&self, index: usize) -> Result<f32, Error> {
+        self.element(index)
+    }
+
+    /// Returns an f64 element.
+    pub fn f64_element(&self, index: usize) -> Result<f64, Error> {
+        self.element(index)
+    }
+
+    /// Returns raw data.
+    ///
+    /// Elements are stored contiguously in their native byte order. Only one
+    /// element is stored for splats. Bool elements are packed into bits.
+    pub fn raw_data(&self) -> Result<&'c [u8], Error> {
+        let element_type = self.element_type();
+        let width = if element_type.is_bf16() || element_type.is_f16() {
+            16
+        } else if element_type.is_f32() {
+            32
+        } else if element_type.is_f64() {
+            64
+        } else if element_type.is_float8e4m3fn() || element_type.is_float8e5m2() {
+            8
+        } else {
+            integer_width(element_type).ok_or_else(|| Error::ElementExpected {
+                r#type: "integer or float",
+                value: self.to_string(),
+            })? as usize
+        };
+        let count = if self.is_splat() { 1 } else { self.len() };
+        let size = if width == 1 {
+            count.div_ceil(8)
+        } else {
+            count * width.div_ceil(8)
+        };
+
+        Ok(unsafe {
+            slice::from_raw_parts(
+                mlirDenseElementsAttrGetRawData(self.attribute.to_raw()) as *const u8,
+                size,
+            )
+        })
+    }
+
+    fn element_type(&self) -> Type<'c> {
+        unsafe {
+            Type::from_raw(mlirShapedTypeGetElementType(mlirAttributeGetType(
+                self.attribute.to_raw(),
+            )))
+        }
+    }
+
     /// Returns an i32 element.
     // TODO Prevent calling these type specific methods on other types.
     pub fn i32_element(&self, index: usize) -> Result<i32, Error> {
@@ -82,6 +399,18 @@ impl<'c> DenseElementsAttribute<'c> {
     }
 }
 
+pub(super) fn static_element_count(r#type: Type) -> Result<usize, Error> {
+    if !r#type.is_shaped() {
+        return Err(Error::TypeExpected("shaped", r#type.to_string()));
+    } else if !unsafe { mlirShapedTypeHasStaticShape(r#type.to_raw()) } {
+        return Err(Error::TypeExpected("statically shaped", r#type.to_string()));
+    }
+
+    Ok((0..unsafe { mlirShapedTypeGetRank(r#type.to_raw()) })
+        .map(|index| unsafe { mlirShapedTypeGetDimSize(r#type.to_raw(), index as isize) } as usize)
+        .product())
+}
+
 attribute_traits!(DenseElementsAttribute, is_dense_elements, "dense elements");
 
 #[cfg(test)]
@@ -90,17 +419,185 @@ mod tests {
     use crate::{
         ir::{
             attribute::IntegerAttribute,
-            r#type::{IntegerType, MemRefType},
+            r#type::{Dimension, IntegerType, MemRefType, RankedTensorType},
         },
         test::create_test_context,
+        Context,
     };
 
+    fn create_tensor_type<'c>(element_type: Type<'c>, dimensions: &[u64]) -> Type<'c> {
+        RankedTensorType::new(
+            &dimensions
+                .iter()
+                .copied()
+                .map(Dimension::Static)
+                .collect::<Vec<_>>(),
+            element_type,
+            None,
+        )
+        .unwrap()
+        .into()
+    }
+
+    #[test]
+    fn from_values() {
+        let context = create_test_context();
+        let r#type = create_tensor_type(IntegerType::new(&context, 16).into(), &[3]);
+
+        assert_eq!(
+            Attribute::from(DenseElementsAttribute::from_values(r#type, &[1i16, 2, 3]).unwrap()),
+            Attribute::parse(&context, "dense<[1, 2, 3]> : tensor<3xi16>").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_float_values() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_values(
+            create_tensor_type(Type::float64(&context), &[2]),
+            &[1.5, 2.5],
+        )
+        .unwrap();
+
+        assert_eq!(attribute.f64_element(0), Ok(1.5));
+        assert_eq!(attribute.f64_element(1), Ok(2.5));
+        assert!(matches!(
+            attribute.f32_element(0),
+            Err(Error::ElementExpected { r#type: "f32", .. })
+        ));
+    }
+
+    #[test]
+    fn from_bool_values() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_values(
+            create_tensor_type(IntegerType::new(&context, 1).into(), &[3]),
+            &[true, false, true],
+        )
+        .unwrap();
+
+        assert_eq!(
+            attribute.elements::<bool>().unwrap().collect::<Vec<_>>(),
+            [true, false, true]
+        );
+    }
+
+    #[test]
+    fn from_values_with_invalid_type() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            DenseElementsAttribute::from_values(
+                create_tensor_type(Type::float32(&context), &[1]),
+                &[1i32]
+            ),
+            Err(Error::ElementExpected { r#type: "i32", .. })
+        ));
+        assert!(matches!(
+            DenseElementsAttribute::from_values(Type::float32(&context), &[1.0f32]),
+            Err(Error::TypeExpected("shaped", _))
+        ));
+        assert!(matches!(
+            DenseElementsAttribute::from_values(
+                Type::parse(&context, "tensor<?xf32>").unwrap(),
+                &[1.0f32]
+            ),
+            Err(Error::TypeExpected("statically shaped", _))
+        ));
+    }
+
+    #[test]
+    fn from_values_with_invalid_length() {
+        let context = create_test_context();
+
+        assert_eq!(
+            DenseElementsAttribute::from_values(
+                create_tensor_type(Type::float32(&context), &[3]),
+                &[1.0f32, 2.0]
+            ),
+            Err(Error::ElementCountMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn splat() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::splat(
+            create_tensor_type(IntegerType::unsigned(&context, 8).into(), &[2, 2]),
+            42u8,
+        )
+        .unwrap();
+
+        assert!(attribute.is_splat());
+        assert_eq!(attribute.len(), 4);
+        assert_eq!(
+            attribute.elements::<u8>().unwrap().collect::<Vec<_>>(),
+            [42; 4]
+        );
+    }
+
+    #[test]
+    fn is_splat() {
+        let context = create_test_context();
+        let attribute = DenseElementsAttribute::from_values(
+            create_tensor_type(IntegerType::new(&context, 64).into(), &[2]),
+            &[1i64, 2],
+        )
+        .unwrap();
+
+        assert!(!attribute.is_splat());
+    }
+
+    fn create_f32_attribute(context: &Context) -> DenseElementsAttribute {
+        DenseElementsAttribute::from_values(
+            create_tensor_type(Type::float32(context), &[2]),
+            &[1.0f32, 2.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn raw_data() {
+        let context = create_test_context();
+
+        assert_eq!(
+            create_f32_attribute(&context).raw_data().unwrap(),
+            [1.0f32.to_ne_bytes(), 2.0f32.to_ne_bytes()].concat()
+        );
+    }
+
+    #[test]
+    fn from_raw_data() {
+        let context = create_test_context();
+        let attribute = create_f32_attribute(&context);
+
+        assert_eq!(
+            DenseElementsAttribute::from_raw_data(
+                create_tensor_type(Type::float32(&context), &[2]),
+                attribute.raw_data().unwrap()
+            ),
+            Ok(attribute)
+        );
+        assert!(matches!(
+            DenseElementsAttribute::from_raw_data(
+                create_tensor_type(Type::float32(&context), &[2]),
+                &[0; 3]
+            ),
+            Err(Error::InvalidRawData(_))
+        ));
+    }
+
     #[test]
     fn i32_element() {
         let context = create_test_context();
         let integer_type = IntegerType::new(&context, 32).into();
         let attribute = DenseElementsAttribute::new(
-            MemRefType::new(integer_type, &[3], None, None).into(),
+            MemRefType::new(integer_type, &[Dimension::Static(3)], None, None)
+                .unwrap()
+                .into(),
             &[IntegerAttribute::new(integer_type, 42).into()],
         )
         .unwrap();
@@ -123,7 +620,9 @@ mod tests {
         let context = create_test_context();
         let integer_type = IntegerType::new(&context, 64).into();
         let attribute = DenseElementsAttribute::new(
-            MemRefType::new(integer_type, &[3], None, None).into(),
+            MemRefType::new(integer_type, &[Dimension::Static(3)], None, None)
+                .unwrap()
+                .into(),
             &[IntegerAttribute::new(integer_type, 42).into()],
         )
         .unwrap();
@@ -146,7 +645,9 @@ mod tests {
         let context = create_test_context();
         let integer_type = IntegerType::new(&context, 64).into();
         let attribute = DenseElementsAttribute::new(
-            MemRefType::new(integer_type, &[3], None, None).into(),
+            MemRefType::new(integer_type, &[Dimension::Static(3)], None, None)
+                .unwrap()
+                .into(),
             &[IntegerAttribute::new(integer_type, 0).into()],
         )
         .unwrap();
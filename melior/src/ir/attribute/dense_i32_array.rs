@@ -5,7 +5,7 @@ use mlir_sys::{
 };
 
 /// A dense i32 array attribute.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 pub struct DenseI32ArrayAttribute<'c> {
     attribute: Attribute<'c>,
 }
@@ -44,6 +44,16 @@ impl<'c> DenseI32ArrayAttribute<'c> {
             })
         }
     }
+
+    /// Returns all elements.
+    pub fn elements(&self) -> impl Iterator<Item = i32> + '_ {
+        (0..self.len()).map(|index| self.element(index).expect("valid element index"))
+    }
+
+    /// Converts an array into a vector.
+    pub fn to_vec(&self) -> Vec<i32> {
+        self.elements().collect()
+    }
 }
 
 attribute_traits!(
@@ -79,4 +89,13 @@ mod tests {
 
         assert_eq!(attribute.len(), 3);
     }
+
+    #[test]
+    fn elements() {
+        let context = create_test_context();
+        let attribute = DenseI32ArrayAttribute::new(&context, &[1, 2, 3]);
+
+        assert_eq!(attribute.elements().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(attribute.to_vec(), [1, 2, 3]);
+    }
 }
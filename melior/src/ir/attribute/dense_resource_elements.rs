@@ -0,0 +1,593 @@
+use super::{dense_elements::static_element_count, Attribute, AttributeLike, DenseElement};
+use crate::{
+    ir::{r#type::IntegerType, Type, TypeLike},
+    Context, Error, StringRef,
+};
+use mlir_sys::{
+    mlirAttributeGetType, mlirDenseDoubleResourceElementsAttrGetValue,
+    mlirDenseFloatResourceElementsAttrGetValue, mlirDenseInt16ResourceElementsAttrGetValue,
+    mlirDenseInt32ResourceElementsAttrGetValue, mlirDenseInt64ResourceElementsAttrGetValue,
+    mlirDenseInt8ResourceElementsAttrGetValue, mlirDenseUInt16ResourceElementsAttrGetValue,
+    mlirDenseUInt32ResourceElementsAttrGetValue, mlirDenseUInt64ResourceElementsAttrGetValue,
+    mlirDenseUInt8ResourceElementsAttrGetValue, mlirElementsAttrGetNumElements,
+    mlirShapedTypeGetElementType, mlirUnmanagedDenseDoubleResourceElementsAttrGet,
+    mlirUnmanagedDenseFloatResourceElementsAttrGet, mlirUnmanagedDenseInt16ResourceElementsAttrGet,
+    mlirUnmanagedDenseInt32ResourceElementsAttrGet, mlirUnmanagedDenseInt64ResourceElementsAttrGet,
+    mlirUnmanagedDenseInt8ResourceElementsAttrGet, mlirUnmanagedDenseUInt16ResourceElementsAttrGet,
+    mlirUnmanagedDenseUInt32ResourceElementsAttrGet,
+    mlirUnmanagedDenseUInt64ResourceElementsAttrGet,
+    mlirUnmanagedDenseUInt8ResourceElementsAttrGet, MlirAttribute, MlirStringRef, MlirType,
+};
+use std::{any::Any, mem::size_of_val, slice};
+
+/// A blob of a dense resource elements attribute with its owner.
+#[derive(Debug)]
+pub(crate) struct ResourceBlob {
+    data: *const u8,
+    length: usize,
+    _owner: Box<dyn Any>,
+}
+
+impl ResourceBlob {
+    /// Returns data of a blob.
+    ///
+    /// # Safety
+    ///
+    /// A returned slice must not outlive a blob.
+    pub unsafe fn as_bytes<'a>(&self) -> &'a [u8] {
+        slice::from_raw_parts(self.data, self.length)
+    }
+}
+
+/// An element of dense resource elements attributes.
+pub trait DenseResourceElement: DenseElement {
+    /// Creates a dense resource elements attribute as a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw type must be a statically shaped type with a valid element type.
+    /// Values are not copied and must outlive the attribute.
+    unsafe fn create_resource_raw(
+        r#type: MlirType,
+        name: MlirStringRef,
+        values: &[Self],
+    ) -> MlirAttribute;
+
+    /// Returns an element of a raw dense resource elements attribute.
+    ///
+    /// # Safety
+    ///
+    /// A raw attribute must have a valid element type and an index must be in
+    /// bounds.
+    unsafe fn resource_element_raw(attribute: MlirAttribute, index: usize) -> Self;
+}
+
+macro_rules! dense_resource_element {
+    ($type:ty, $create:ident, $element:ident) => {
+        impl DenseResourceElement for $type {
+            unsafe fn create_resource_raw(
+                r#type: MlirType,
+                name: MlirStringRef,
+                values: &[Self],
+            ) -> MlirAttribute {
+                $create(r#type, name, values.len() as isize, values.as_ptr())
+            }
+
+            unsafe fn resource_element_raw(attribute: MlirAttribute, index: usize) -> Self {
+                $element(attribute, index as isize)
+            }
+        }
+    };
+}
+
+dense_resource_element!(
+    i8,
+    mlirUnmanagedDenseInt8ResourceElementsAttrGet,
+    mlirDenseInt8ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    u8,
+    mlirUnmanagedDenseUInt8ResourceElementsAttrGet,
+    mlirDenseUInt8ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    i16,
+    mlirUnmanagedDenseInt16ResourceElementsAttrGet,
+    mlirDenseInt16ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    u16,
+    mlirUnmanagedDenseUInt16ResourceElementsAttrGet,
+    mlirDenseUInt16ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    i32,
+    mlirUnmanagedDenseInt32ResourceElementsAttrGet,
+    mlirDenseInt32ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    u32,
+    mlirUnmanagedDenseUInt32ResourceElementsAttrGet,
+    mlirDenseUInt32ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    i64,
+    mlirUnmanagedDenseInt64ResourceElementsAttrGet,
+    mlirDenseInt64ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    u64,
+    mlirUnmanagedDenseUInt64ResourceElementsAttrGet,
+    mlirDenseUInt64ResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    f32,
+    mlirUnmanagedDenseFloatResourceElementsAttrGet,
+    mlirDenseFloatResourceElementsAttrGetValue
+);
+dense_resource_element!(
+    f64,
+    mlirUnmanagedDenseDoubleResourceElementsAttrGet,
+    mlirDenseDoubleResourceElementsAttrGetValue
+);
+
+/// A dense resource elements attribute.
+///
+/// It refers to a named blob of elements stored outside of the attribute.
+/// Blobs are printed in a `dialect_resources` section of operations.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct DenseResourceElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DenseResourceElementsAttribute<'c> {
+    /// Creates a dense resource elements attribute referring to a blob of a
+    /// given name.
+    ///
+    /// Values are not copied. Their owner, such as `Vec<T>`, `Box<[T]>`,
+    /// `Arc<[T]>` or `&'static [T]`, is kept by a given context, which must be
+    /// the one of a type, until the context is dropped. The context retains
+    /// every owner passed to it even after no attribute refers to it, so a
+    /// long-lived context keeps all blobs created in it in memory.
+    pub fn new<T: DenseResourceElement, V: AsRef<[T]> + 'static>(
+        context: &'c Context,
+        r#type: Type<'c>,
+        name: &str,
+        values: V,
+    ) -> Result<Self, Error> {
+        let values = Box::new(values);
+        let (pointer, length) = {
+            let values = (*values).as_ref();
+
+            (values.as_ptr(), values.len())
+        };
+
+        // The heap allocation of the owner does not move with the box.
+        Self::from_blob(
+            context,
+            r#type,
+            name,
+            unsafe { slice::from_raw_parts(pointer, length) },
+            values,
+        )
+    }
+
+    /// Creates a dense resource elements attribute from raw bytes.
+    ///
+    /// Bytes are not copied and are interpreted as elements of a given type,
+    /// so they must be aligned for it. Their owner is kept by a given context
+    /// as in [`DenseResourceElementsAttribute::new`].
+    pub fn from_bytes<V: AsRef<[u8]> + 'static>(
+        context: &'c Context,
+        r#type: Type<'c>,
+        name: &str,
+        bytes: V,
+    ) -> Result<Self, Error> {
+        let bytes = Box::new(bytes);
+        let bytes_slice = {
+            let bytes = (*bytes).as_ref();
+
+            // The heap allocation of the owner does not move with the box.
+            unsafe { slice::from_raw_parts(bytes.as_ptr(), bytes.len()) }
+        };
+        let element_type = unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) };
+
+        if let Ok(integer_type) = IntegerType::try_from(element_type) {
+            match (integer_type.width(), integer_type.is_unsigned()) {
+                (8, false) => {
+                    Self::from_bytes_blob::<i8>(context, r#type, name, bytes_slice, bytes)
+                }
+                (8, true) => Self::from_bytes_blob::<u8>(context, r#type, name, bytes_slice, bytes),
+                (16, false) => {
+                    Self::from_bytes_blob::<i16>(context, r#type, name, bytes_slice, bytes)
+                }
+                (16, true) => {
+                    Self::from_bytes_blob::<u16>(context, r#type, name, bytes_slice, bytes)
+                }
+                (32, false) => {
+                    Self::from_bytes_blob::<i32>(context, r#type, name, bytes_slice, bytes)
+                }
+                (32, true) => {
+                    Self::from_bytes_blob::<u32>(context, r#type, name, bytes_slice, bytes)
+                }
+                (64, false) => {
+                    Self::from_bytes_blob::<i64>(context, r#type, name, bytes_slice, bytes)
+                }
+                (64, true) => {
+                    Self::from_bytes_blob::<u64>(context, r#type, name, bytes_slice, bytes)
+                }
+                _ => Err(Error::InvalidRawData(r#type.to_string())),
+            }
+        } else if element_type.is_f32() {
+            Self::from_bytes_blob::<f32>(context, r#type, name, bytes_slice, bytes)
+        } else if element_type.is_f64() {
+            Self::from_bytes_blob::<f64>(context, r#type, name, bytes_slice, bytes)
+        } else {
+            Err(Error::InvalidRawData(r#type.to_string()))
+        }
+    }
+
+    fn from_bytes_blob<T: DenseResourceElement>(
+        context: &'c Context,
+        r#type: Type<'c>,
+        name: &str,
+        bytes: &[u8],
+        owner: Box<dyn Any>,
+    ) -> Result<Self, Error> {
+        let (prefix, values, suffix) = unsafe { bytes.align_to::<T>() };
+
+        if !prefix.is_empty() || !suffix.is_empty() {
+            return Err(Error::InvalidRawData(r#type.to_string()));
+        }
+
+        Self::from_blob(context, r#type, name, values, owner)
+    }
+
+    fn from_blob<T: DenseResourceElement>(
+        context: &'c Context,
+        r#type: Type<'c>,
+        name: &str,
+        values: &[T],
+        owner: Box<dyn Any>,
+    ) -> Result<Self, Error> {
+        if r#type.context() != *context {
+            return Err(Error::ContextMismatch(r#type.to_string()));
+        }
+
+        let length = static_element_count(r#type)?;
+        let element_type = unsafe { Type::from_raw(mlirShapedTypeGetElementType(r#type.to_raw())) };
+
+        if !T::is_element_type(element_type) {
+            return Err(Error::ElementExpected {
+                r#type: T::name(),
+                value: r#type.to_string(),
+            });
+        } else if values.len() != length {
+            return Err(Error::ElementCountMismatch {
+                expected: length,
+                actual: values.len(),
+            });
+        }
+
+        // TODO Pass a deleter with `mlirUnmanagedDenseResourceElementsAttrGet`
+        // when we upgrade to MLIR 18. Until then, MLIR cannot tell when a blob
+        // is released, so its owner lives as long as its context.
+        let attribute = unsafe {
+            Self::from_raw(T::create_resource_raw(
+                r#type.to_raw(),
+                StringRef::new(name).to_raw(),
+                values,
+            ))
+        };
+
+        context.add_resource_blob(
+            attribute.to_raw(),
+            ResourceBlob {
+                data: values.as_ptr() as *const u8,
+                length: size_of_val(values),
+                _owner: owner,
+            },
+        );
+
+        Ok(attribute)
+    }
+
+    /// Returns a blob as raw bytes without copying it.
+    ///
+    /// It is available only for attributes created by Melior in a given
+    /// context. Blobs of parsed attributes are owned by MLIR and not exposed by
+    /// its C API.
+    pub fn blob(&self, context: &'c Context) -> Option<&'c [u8]> {
+        context.resource_blob(self.to_raw())
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirElementsAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if an array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an element.
+    pub fn element<T: DenseResourceElement>(&self, index: usize) -> Result<T, Error> {
+        if !T::is_element_type(self.element_type()) {
+            Err(Error::ElementExpected {
+                r#type: T::name(),
+                value: self.to_string(),
+            })
+        } else if index < self.len() {
+            Ok(unsafe { T::resource_element_raw(self.attribute.to_raw(), index) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dense resource element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all elements.
+    pub fn elements<T: DenseResourceElement>(&self) -> Result<impl Iterator<Item = T> + '_, Error> {
+        if T::is_element_type(self.element_type()) {
+            Ok((0..self.len())
+                .map(|index| unsafe { T::resource_element_raw(self.attribute.to_raw(), index) }))
+        } else {
+            Err(Error::ElementExpected {
+                r#type: T::name(),
+                value: self.to_string(),
+            })
+        }
+    }
+
+    fn element_type(&self) -> Type<'c> {
+        unsafe {
+            Type::from_raw(mlirShapedTypeGetElementType(mlirAttributeGetType(
+                self.attribute.to_raw(),
+            )))
+        }
+    }
+}
+
+attribute_traits!(
+    DenseResourceElementsAttribute,
+    is_dense_resource_elements,
+    "dense resource elements"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialect::arith,
+        ir::{
+            r#type::{Dimension, RankedTensorType},
+            Location, Module,
+        },
+        test::create_test_context,
+        Context,
+    };
+    use std::sync::Arc;
+
+    static VALUES: [f32; 3] = [1.0, 2.0, 3.0];
+
+    #[repr(align(8))]
+    struct AlignedBytes<const N: usize>([u8; N]);
+
+    impl<const N: usize> AsRef<[u8]> for AlignedBytes<N> {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    fn create_integer_type(context: &Context) -> Type {
+        RankedTensorType::new(
+            &[Dimension::Static(3)],
+            IntegerType::new(context, 32).into(),
+            None,
+        )
+        .unwrap()
+        .into()
+    }
+
+    fn create_type(context: &Context, length: u64) -> Type {
+        RankedTensorType::new(&[Dimension::Static(length)], Type::float32(context), None)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let attribute =
+            DenseResourceElementsAttribute::new(&context, create_type(&context, 3), "foo", VALUES)
+                .unwrap();
+
+        assert_eq!(attribute.to_string(), "dense_resource<foo> : tensor<3xf32>");
+        assert_eq!(attribute.len(), 3);
+    }
+
+    #[test]
+    fn new_with_owned_values() {
+        let context = create_test_context();
+        let values = Arc::<[f32]>::from(VALUES);
+        let attribute = DenseResourceElementsAttribute::new(
+            &context,
+            create_type(&context, 3),
+            "foo",
+            values.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(Arc::strong_count(&values), 2);
+        assert_eq!(
+            attribute.elements::<f32>().unwrap().collect::<Vec<_>>(),
+            VALUES
+        );
+
+        drop(context);
+
+        assert_eq!(Arc::strong_count(&values), 1);
+    }
+
+    #[test]
+    fn from_bytes() {
+        let context = create_test_context();
+        let mut bytes = AlignedBytes([0; 12]);
+
+        for (chunk, value) in bytes.0.chunks_mut(4).zip([1i32, 2, 3]) {
+            chunk.copy_from_slice(&value.to_ne_bytes());
+        }
+
+        let expected = bytes.0;
+        let attribute = DenseResourceElementsAttribute::from_bytes(
+            &context,
+            create_integer_type(&context),
+            "foo",
+            bytes,
+        )
+        .unwrap();
+
+        assert_eq!(
+            attribute.elements::<i32>().unwrap().collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(attribute.blob(&context), Some(expected.as_slice()));
+    }
+
+    #[test]
+    fn from_bytes_with_invalid_bytes() {
+        let context = create_test_context();
+
+        assert_eq!(
+            DenseResourceElementsAttribute::from_bytes(
+                &context,
+                create_integer_type(&context),
+                "foo",
+                AlignedBytes([0; 4])
+            ),
+            Err(Error::ElementCountMismatch {
+                expected: 3,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            DenseResourceElementsAttribute::from_bytes(
+                &context,
+                RankedTensorType::new(&[Dimension::Static(3)], Type::index(&context), None)
+                    .unwrap()
+                    .into(),
+                "foo",
+                AlignedBytes([0; 12])
+            ),
+            Err(Error::InvalidRawData("tensor<3xindex>".into()))
+        );
+    }
+
+    #[test]
+    fn blob() {
+        let context = create_test_context();
+        let attribute = DenseResourceElementsAttribute::new(
+            &context,
+            create_integer_type(&context),
+            "foo",
+            vec![1i32, 2, 3],
+        )
+        .unwrap();
+
+        assert_eq!(
+            attribute.blob(&context),
+            Some([1i32, 2, 3].map(i32::to_ne_bytes).concat().as_slice())
+        );
+        assert_eq!(
+            DenseResourceElementsAttribute::try_from(
+                Attribute::parse(&context, "dense_resource<bar> : tensor<3xf32>").unwrap()
+            )
+            .unwrap()
+            .blob(&context),
+            None
+        );
+    }
+
+    #[test]
+    fn new_in_other_context() {
+        let context = create_test_context();
+        let other_context = create_test_context();
+
+        assert_eq!(
+            DenseResourceElementsAttribute::new(
+                &other_context,
+                create_type(&context, 3),
+                "foo",
+                VALUES
+            ),
+            Err(Error::ContextMismatch("tensor<3xf32>".into()))
+        );
+    }
+
+    #[test]
+    fn new_with_invalid_values() {
+        let context = create_test_context();
+
+        assert_eq!(
+            DenseResourceElementsAttribute::new(&context, create_type(&context, 2), "foo", VALUES),
+            Err(Error::ElementCountMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert!(matches!(
+            DenseResourceElementsAttribute::new(
+                &context,
+                create_type(&context, 3),
+                "foo",
+                [1i32, 2, 3]
+            ),
+            Err(Error::ElementExpected { r#type: "i32", .. })
+        ));
+    }
+
+    #[test]
+    fn elements() {
+        let context = create_test_context();
+        let attribute =
+            DenseResourceElementsAttribute::new(&context, create_type(&context, 3), "foo", VALUES)
+                .unwrap();
+
+        assert_eq!(attribute.element::<f32>(1), Ok(2.0));
+        assert_eq!(
+            attribute.elements::<f32>().unwrap().collect::<Vec<_>>(),
+            VALUES
+        );
+        assert!(matches!(
+            attribute.element::<f32>(3),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            attribute.elements::<f64>(),
+            Err(Error::ElementExpected { r#type: "f64", .. })
+        ));
+    }
+
+    #[test]
+    fn print_resource() {
+        let context = create_test_context();
+        let location = Location::unknown(&context);
+        let module = Module::new(location);
+        let attribute =
+            DenseResourceElementsAttribute::new(&context, create_type(&context, 3), "foo", VALUES)
+                .unwrap();
+
+        module
+            .body()
+            .append_operation(arith::constant(&context, attribute.into(), location));
+
+        let string = module.as_operation().to_string();
+
+        assert!(string.contains("dense_resource<foo>"));
+        assert!(string.contains("dialect_resources"));
+        assert!(string.contains("foo: \"0x"));
+    }
+}
@@ -0,0 +1,162 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::Identifier, Context, Error, StringRef};
+use mlir_sys::{
+    mlirDictionaryAttrGet, mlirDictionaryAttrGetElement, mlirDictionaryAttrGetElementByName,
+    mlirDictionaryAttrGetNumElements, mlirNamedAttributeGet, MlirAttribute,
+};
+
+/// A dictionary attribute.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct DictionaryAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> DictionaryAttribute<'c> {
+    /// Creates a dictionary attribute.
+    pub fn new(context: &'c Context, elements: &[(Identifier<'c>, Attribute<'c>)]) -> Self {
+        let elements = elements
+            .iter()
+            .map(|(identifier, attribute)| unsafe {
+                mlirNamedAttributeGet(identifier.to_raw(), attribute.to_raw())
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirDictionaryAttrGet(
+                context.to_raw(),
+                elements.len() as isize,
+                elements.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns a length.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirDictionaryAttrGetNumElements(self.attribute.to_raw()) }) as usize
+    }
+
+    /// Checks if a dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an element at a position.
+    pub fn element(&self, index: usize) -> Result<(Identifier<'c>, Attribute<'c>), Error> {
+        if index < self.len() {
+            unsafe {
+                let named_attribute =
+                    mlirDictionaryAttrGetElement(self.attribute.to_raw(), index as isize);
+
+                Ok((
+                    Identifier::from_raw(named_attribute.name),
+                    Attribute::from_raw(named_attribute.attribute),
+                ))
+            }
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "dictionary element",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all elements.
+    pub fn elements(&self) -> impl Iterator<Item = (Identifier<'c>, Attribute<'c>)> + '_ {
+        (0..self.len()).map(|index| self.element(index).expect("valid element index"))
+    }
+
+    /// Returns an element with a given name.
+    pub fn get(&self, name: &str) -> Result<Attribute<'c>, Error> {
+        unsafe {
+            Attribute::from_option_raw(mlirDictionaryAttrGetElementByName(
+                self.attribute.to_raw(),
+                StringRef::new(name).to_raw(),
+            ))
+        }
+        .ok_or(Error::AttributeNotFound(name.into()))
+    }
+}
+
+attribute_traits!(DictionaryAttribute, is_dictionary, "dictionary");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::attribute::StringAttribute, test::create_test_context};
+
+    fn create_dictionary(context: &Context) -> DictionaryAttribute {
+        DictionaryAttribute::new(
+            context,
+            &[
+                (
+                    Identifier::new(context, "foo"),
+                    StringAttribute::new(context, "bar").into(),
+                ),
+                (Identifier::new(context, "baz"), Attribute::unit(context)),
+            ],
+        )
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(create_dictionary(&context)),
+            Attribute::parse(&context, "{baz, foo = \"bar\"}").unwrap()
+        );
+    }
+
+    #[test]
+    fn len() {
+        let context = create_test_context();
+
+        assert_eq!(create_dictionary(&context).len(), 2);
+        assert!(DictionaryAttribute::new(&context, &[]).is_empty());
+    }
+
+    #[test]
+    fn element() {
+        let context = create_test_context();
+        let dictionary = create_dictionary(&context);
+
+        // Elements are sorted by names.
+        assert_eq!(
+            dictionary.element(0).unwrap(),
+            (Identifier::new(&context, "baz"), Attribute::unit(&context))
+        );
+        assert!(matches!(
+            dictionary.element(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn elements() {
+        let context = create_test_context();
+
+        assert_eq!(
+            create_dictionary(&context)
+                .elements()
+                .map(|(identifier, _)| identifier.as_string_ref().as_str().unwrap().to_owned())
+                .collect::<Vec<_>>(),
+            ["baz", "foo"]
+        );
+    }
+
+    #[test]
+    fn get() {
+        let context = create_test_context();
+        let dictionary = create_dictionary(&context);
+
+        assert_eq!(
+            dictionary.get("foo").unwrap(),
+            StringAttribute::new(&context, "bar").into()
+        );
+        assert!(matches!(
+            dictionary.get("qux"),
+            Err(Error::AttributeNotFound(_))
+        ));
+    }
+}
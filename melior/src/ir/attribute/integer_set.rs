@@ -0,0 +1,237 @@
+use super::{Attribute, AttributeLike};
+use crate::{
+    ir::{AffineMap, IntegerSet, IntegerSetConstraint},
+    Error, StringRef,
+};
+use mlir_sys::{
+    mlirAffineMapAttrGetValue, mlirAttributeGetContext, mlirAttributeIsAAffineMap,
+    mlirAttributeParseGet, mlirIntegerSetGetContext, MlirAttribute,
+};
+
+/// An integer set attribute.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct IntegerSetAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> IntegerSetAttribute<'c> {
+    /// Creates an integer set attribute.
+    pub fn new(set: IntegerSet<'c>) -> Result<Self, Error> {
+        // TODO Use `mlirIntegerSetAttrGet` when we upgrade to MLIR 18.
+        let raw = unsafe {
+            mlirAttributeParseGet(
+                mlirIntegerSetGetContext(set.to_raw()),
+                StringRef::new(&format!("affine_set<{set}>")).to_raw(),
+            )
+        };
+
+        if raw.ptr.is_null() {
+            Err(Error::AttributeExpected("integer set", set.to_string()))
+        } else {
+            Ok(unsafe { Self::from_raw(raw) })
+        }
+    }
+
+    /// Returns an integer set value.
+    pub fn value(&self) -> Result<IntegerSet<'c>, Error> {
+        // TODO Use `mlirIntegerSetAttrGetValue` when we upgrade to MLIR 18.
+        // Until then, constraints are parsed back as results of an affine map
+        // with the same inputs.
+        let source = self.to_string();
+        let error = || Error::AttributeExpected("integer set", source.clone());
+        let (inputs, constraints) = source
+            .strip_prefix("affine_set<")
+            .and_then(|source| source.strip_suffix('>'))
+            .and_then(|source| source.split_once(" : "))
+            .and_then(|(inputs, constraints)| {
+                Some((inputs, constraints.strip_prefix('(')?.strip_suffix(')')?))
+            })
+            .ok_or_else(error)?;
+        let constraints = split_top_level(constraints)
+            .into_iter()
+            .map(|constraint| {
+                if let Some(expression) = constraint.strip_suffix(" == 0") {
+                    Some((expression, true))
+                } else {
+                    Some((constraint.strip_suffix(" >= 0")?, false))
+                }
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(error)?;
+
+        unsafe {
+            let context = mlirAttributeGetContext(self.to_raw());
+            let map = mlirAttributeParseGet(
+                context,
+                StringRef::new(&format!(
+                    "affine_map<{inputs} -> ({})>",
+                    constraints
+                        .iter()
+                        .map(|(expression, _)| *expression)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+                .to_raw(),
+            );
+
+            if map.ptr.is_null() || !mlirAttributeIsAAffineMap(map) {
+                return Err(error());
+            }
+
+            let map = AffineMap::from_raw(mlirAffineMapAttrGetValue(map));
+
+            Ok(IntegerSet::from_context_raw(
+                context,
+                map.dimension_count(),
+                map.symbol_count(),
+                &map.results()
+                    .zip(constraints)
+                    .map(|(expression, (_, equality))| {
+                        if equality {
+                            IntegerSetConstraint::Equality(expression)
+                        } else {
+                            IntegerSetConstraint::Inequality(expression)
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            ))
+        }
+    }
+}
+
+// Splits a string by commas outside parentheses.
+fn split_top_level(source: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, character) in source.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(source[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(source[start..].trim());
+
+    parts
+}
+
+attribute_traits!(IntegerSetAttribute, is_integer_set, "integer set");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::AffineExpr, test::create_test_context};
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+        let set = IntegerSet::new(
+            &context,
+            1,
+            0,
+            &[IntegerSetConstraint::Inequality(
+                AffineExpr::dimension(&context, 0) - 2,
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(
+            Attribute::from(IntegerSetAttribute::new(set).unwrap()),
+            Attribute::parse(&context, "affine_set<(d0) : (d0 - 2 >= 0)>").unwrap()
+        );
+    }
+
+    #[test]
+    fn value() {
+        let context = create_test_context();
+        let dimension = AffineExpr::dimension(&context, 0);
+        let symbol = AffineExpr::symbol(&context, 0);
+        let set = IntegerSet::new(
+            &context,
+            2,
+            1,
+            &[
+                IntegerSetConstraint::Inequality(
+                    (dimension + 1).floor_div(AffineExpr::constant(&context, 2)) - 2,
+                ),
+                IntegerSetConstraint::Equality(symbol - dimension),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(IntegerSetAttribute::new(set).unwrap().value(), Ok(set));
+        assert_eq!(
+            IntegerSetAttribute::new(IntegerSet::empty(&context, 1, 0))
+                .unwrap()
+                .value(),
+            Ok(IntegerSet::empty(&context, 1, 0))
+        );
+    }
+
+    #[test]
+    fn value_with_parenthesized_constraints() {
+        let context = create_test_context();
+        let dimension = AffineExpr::dimension(&context, 0);
+        let symbol = AffineExpr::symbol(&context, 0);
+        let set = IntegerSet::new(
+            &context,
+            2,
+            1,
+            &[
+                IntegerSetConstraint::Inequality(
+                    (dimension + 1) % AffineExpr::constant(&context, 2),
+                ),
+                IntegerSetConstraint::Equality(
+                    (AffineExpr::dimension(&context, 1) + symbol)
+                        .floor_div(AffineExpr::constant(&context, 4))
+                        - 1,
+                ),
+                IntegerSetConstraint::Inequality(
+                    (dimension * 2 + symbol).ceil_div(AffineExpr::constant(&context, 3)),
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(IntegerSetAttribute::new(set).unwrap().value(), Ok(set));
+    }
+
+    #[test]
+    fn value_of_parsed_attribute() {
+        let context = create_test_context();
+        let attribute = IntegerSetAttribute::try_from(
+            Attribute::parse(
+                &context,
+                "affine_set<(d0, d1)[s0] : ((d0 + 1) mod 2 >= 0, (d1 + s0) floordiv 4 - 1 == 0)>",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            IntegerSetAttribute::new(attribute.value().unwrap()).unwrap(),
+            attribute
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(IntegerSetAttribute::try_from(
+            Attribute::parse(&context, "affine_set<(d0) : (d0 >= 0)>").unwrap()
+        )
+        .is_ok());
+        assert!(matches!(
+            IntegerSetAttribute::try_from(Attribute::parse(&context, "[42]").unwrap()),
+            Err(Error::AttributeExpected("integer set", _))
+        ));
+    }
+}
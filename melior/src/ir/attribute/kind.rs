@@ -0,0 +1,294 @@
+use super::{
+    AffineMapAttribute, ArrayAttribute, Attribute, AttributeLike, BoolAttribute,
+    DenseBoolArrayAttribute, DenseElementsAttribute, DenseF32ArrayAttribute,
+    DenseF64ArrayAttribute, DenseI16ArrayAttribute, DenseI32ArrayAttribute, DenseI64ArrayAttribute,
+    DenseI8ArrayAttribute, DenseResourceElementsAttribute, DictionaryAttribute,
+    FlatSymbolRefAttribute, FloatAttribute, IntegerAttribute, IntegerSetAttribute, OpaqueAttribute,
+    SparseElementsAttribute, SparseTensorEncodingAttribute, StridedLayoutAttribute,
+    StringAttribute, SymbolRefAttribute, TypeAttribute,
+};
+
+/// A kind of a builtin attribute.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttributeKind<'c> {
+    /// An affine map attribute.
+    AffineMap(AffineMapAttribute<'c>),
+    /// An array attribute.
+    Array(ArrayAttribute<'c>),
+    /// A bool attribute.
+    Bool(BoolAttribute<'c>),
+    /// A dense bool array attribute.
+    DenseBoolArray(DenseBoolArrayAttribute<'c>),
+    /// A dense elements attribute.
+    DenseElements(DenseElementsAttribute<'c>),
+    /// A dense f32 array attribute.
+    DenseF32Array(DenseF32ArrayAttribute<'c>),
+    /// A dense f64 array attribute.
+    DenseF64Array(DenseF64ArrayAttribute<'c>),
+    /// A dense i8 array attribute.
+    DenseI8Array(DenseI8ArrayAttribute<'c>),
+    /// A dense i16 array attribute.
+    DenseI16Array(DenseI16ArrayAttribute<'c>),
+    /// A dense i32 array attribute.
+    DenseI32Array(DenseI32ArrayAttribute<'c>),
+    /// A dense i64 array attribute.
+    DenseI64Array(DenseI64ArrayAttribute<'c>),
+    /// A dense resource elements attribute.
+    DenseResourceElements(DenseResourceElementsAttribute<'c>),
+    /// A dictionary attribute.
+    Dictionary(DictionaryAttribute<'c>),
+    /// A flat symbol reference attribute.
+    FlatSymbolRef(FlatSymbolRefAttribute<'c>),
+    /// A float attribute.
+    Float(FloatAttribute<'c>),
+    /// An integer attribute.
+    Integer(IntegerAttribute<'c>),
+    /// An integer set attribute.
+    IntegerSet(IntegerSetAttribute<'c>),
+    /// An opaque attribute.
+    Opaque(OpaqueAttribute<'c>),
+    /// A sparse elements attribute.
+    SparseElements(SparseElementsAttribute<'c>),
+    /// A sparse tensor encoding attribute.
+    SparseTensorEncoding(SparseTensorEncodingAttribute<'c>),
+    /// A strided layout attribute.
+    StridedLayout(StridedLayoutAttribute<'c>),
+    /// A string attribute.
+    String(StringAttribute<'c>),
+    /// A symbol reference attribute.
+    SymbolRef(SymbolRefAttribute<'c>),
+    /// A type attribute.
+    Type(TypeAttribute<'c>),
+    /// A unit attribute.
+    Unit,
+    /// An attribute of any other kind.
+    Other(Attribute<'c>),
+}
+
+impl<'c> From<Attribute<'c>> for AttributeKind<'c> {
+    fn from(attribute: Attribute<'c>) -> Self {
+        // Bool attributes are integer ones and flat symbol references are symbol
+        // references. So they need to be checked first.
+        if let Ok(attribute) = BoolAttribute::try_from(attribute) {
+            Self::Bool(attribute)
+        } else if let Ok(attribute) = FlatSymbolRefAttribute::try_from(attribute) {
+            Self::FlatSymbolRef(attribute)
+        } else if let Ok(attribute) = AffineMapAttribute::try_from(attribute) {
+            Self::AffineMap(attribute)
+        } else if let Ok(attribute) = ArrayAttribute::try_from(attribute) {
+            Self::Array(attribute)
+        } else if let Ok(attribute) = DenseBoolArrayAttribute::try_from(attribute) {
+            Self::DenseBoolArray(attribute)
+        } else if let Ok(attribute) = DenseElementsAttribute::try_from(attribute) {
+            Self::DenseElements(attribute)
+        } else if let Ok(attribute) = DenseF32ArrayAttribute::try_from(attribute) {
+            Self::DenseF32Array(attribute)
+        } else if let Ok(attribute) = DenseF64ArrayAttribute::try_from(attribute) {
+            Self::DenseF64Array(attribute)
+        } else if let Ok(attribute) = DenseI8ArrayAttribute::try_from(attribute) {
+            Self::DenseI8Array(attribute)
+        } else if let Ok(attribute) = DenseI16ArrayAttribute::try_from(attribute) {
+            Self::DenseI16Array(attribute)
+        } else if let Ok(attribute) = DenseI32ArrayAttribute::try_from(attribute) {
+            Self::DenseI32Array(attribute)
+        } else if let Ok(attribute) = DenseI64ArrayAttribute::try_from(attribute) {
+            Self::DenseI64Array(attribute)
+        } else if let Ok(attribute) = DenseResourceElementsAttribute::try_from(attribute) {
+            Self::DenseResourceElements(attribute)
+        } else if let Ok(attribute) = DictionaryAttribute::try_from(attribute) {
+            Self::Dictionary(attribute)
+        } else if let Ok(attribute) = FloatAttribute::try_from(attribute) {
+            Self::Float(attribute)
+        } else if let Ok(attribute) = IntegerAttribute::try_from(attribute) {
+            Self::Integer(attribute)
+        } else if let Ok(attribute) = IntegerSetAttribute::try_from(attribute) {
+            Self::IntegerSet(attribute)
+        } else if let Ok(attribute) = OpaqueAttribute::try_from(attribute) {
+            Self::Opaque(attribute)
+        } else if let Ok(attribute) = SparseElementsAttribute::try_from(attribute) {
+            Self::SparseElements(attribute)
+        } else if let Ok(attribute) = SparseTensorEncodingAttribute::try_from(attribute) {
+            Self::SparseTensorEncoding(attribute)
+        } else if let Ok(attribute) = StridedLayoutAttribute::try_from(attribute) {
+            Self::StridedLayout(attribute)
+        } else if let Ok(attribute) = StringAttribute::try_from(attribute) {
+            Self::String(attribute)
+        } else if let Ok(attribute) = SymbolRefAttribute::try_from(attribute) {
+            Self::SymbolRef(attribute)
+        } else if let Ok(attribute) = TypeAttribute::try_from(attribute) {
+            Self::Type(attribute)
+        } else if attribute.is_unit() {
+            Self::Unit
+        } else {
+            Self::Other(attribute)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::Type, test::create_test_context, Context};
+
+    fn parse_kind<'c>(context: &'c Context, source: &str) -> AttributeKind<'c> {
+        Attribute::parse(context, source).unwrap().kind()
+    }
+
+    #[test]
+    fn scalar() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "true"),
+            AttributeKind::Bool(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "42 : i64"),
+            AttributeKind::Integer(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "4.2 : f32"),
+            AttributeKind::Float(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "\"foo\""),
+            AttributeKind::String(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "index"),
+            AttributeKind::Type(_)
+        ));
+        assert_eq!(parse_kind(&context, "unit"), AttributeKind::Unit);
+    }
+
+    #[test]
+    fn symbol_ref() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "@foo"),
+            AttributeKind::FlatSymbolRef(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "@foo::@bar"),
+            AttributeKind::SymbolRef(_)
+        ));
+    }
+
+    #[test]
+    fn container() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "[42]"),
+            AttributeKind::Array(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "{foo = 42}"),
+            AttributeKind::Dictionary(_)
+        ));
+    }
+
+    #[test]
+    fn dense_array() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "array<i1: true>"),
+            AttributeKind::DenseBoolArray(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "array<i8: 1>"),
+            AttributeKind::DenseI8Array(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "array<i16: 1>"),
+            AttributeKind::DenseI16Array(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "array<i32: 1>"),
+            AttributeKind::DenseI32Array(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "array<i64: 1>"),
+            AttributeKind::DenseI64Array(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "array<f32: 1.0>"),
+            AttributeKind::DenseF32Array(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "array<f64: 1.0>"),
+            AttributeKind::DenseF64Array(_)
+        ));
+    }
+
+    #[test]
+    fn elements() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "dense<42> : tensor<2xi8>"),
+            AttributeKind::DenseElements(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "dense<4.2> : tensor<2xf32>"),
+            AttributeKind::DenseElements(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "dense_resource<foo> : tensor<2xf32>"),
+            AttributeKind::DenseResourceElements(_)
+        ));
+        assert!(matches!(
+            parse_kind(
+                &context,
+                "sparse<[[0, 0], [1, 2]], [1, 5]> : tensor<3x4xi32>"
+            ),
+            AttributeKind::SparseElements(_)
+        ));
+    }
+
+    #[test]
+    fn opaque() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            Attribute::from(OpaqueAttribute::new(
+                &context,
+                "foo",
+                "bar",
+                Type::none(&context)
+            ))
+            .kind(),
+            AttributeKind::Opaque(_)
+        ));
+    }
+
+    #[test]
+    fn affine() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "affine_map<(d0) -> (d0)>"),
+            AttributeKind::AffineMap(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "affine_set<(d0) : (d0 - 2 >= 0)>"),
+            AttributeKind::IntegerSet(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "strided<[1]>"),
+            AttributeKind::StridedLayout(_)
+        ));
+    }
+
+    #[test]
+    fn other() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "#llvm.linkage<internal>"),
+            AttributeKind::Other(_)
+        ));
+    }
+}
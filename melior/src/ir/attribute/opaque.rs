@@ -0,0 +1,100 @@
+use super::{Attribute, AttributeLike};
+use crate::{
+    ir::{Type, TypeLike},
+    Context, Error, StringRef,
+};
+use mlir_sys::{
+    mlirOpaqueAttrGet, mlirOpaqueAttrGetData, mlirOpaqueAttrGetDialectNamespace, MlirAttribute,
+};
+
+/// An opaque attribute.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct OpaqueAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> OpaqueAttribute<'c> {
+    /// Creates an opaque attribute.
+    pub fn new(context: &'c Context, namespace: &str, data: &str, r#type: Type<'c>) -> Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueAttrGet(
+                context.to_raw(),
+                StringRef::new(namespace).to_raw(),
+                data.len() as isize,
+                data.as_ptr() as *const _,
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Returns a dialect namespace.
+    pub fn namespace(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueAttrGetDialectNamespace(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns data.
+    pub fn data(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueAttrGetData(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+}
+
+attribute_traits!(OpaqueAttribute, is_opaque, "opaque");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespace() {
+        let context = Context::new();
+
+        assert_eq!(
+            OpaqueAttribute::new(&context, "foo", "bar", Type::none(&context)).namespace(),
+            "foo"
+        );
+    }
+
+    #[test]
+    fn data() {
+        let context = Context::new();
+
+        assert_eq!(
+            OpaqueAttribute::new(&context, "foo", "bar", Type::none(&context)).data(),
+            "bar"
+        );
+    }
+
+    #[test]
+    fn r#type() {
+        let context = Context::new();
+        let r#type = Type::index(&context);
+
+        assert_eq!(
+            OpaqueAttribute::new(&context, "foo", "bar", r#type).r#type(),
+            r#type
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(
+            OpaqueAttribute::try_from(Attribute::from(OpaqueAttribute::new(
+                &context,
+                "foo",
+                "bar",
+                Type::none(&context)
+            )))
+            .is_ok()
+        );
+        assert!(matches!(
+            OpaqueAttribute::try_from(Attribute::unit(&context)),
+            Err(Error::AttributeExpected("opaque", _))
+        ));
+    }
+}
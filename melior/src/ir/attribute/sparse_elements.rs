@@ -0,0 +1,206 @@
+use super::{Attribute, AttributeLike, DenseElement, DenseElementsAttribute};
+use crate::{
+    ir::{Type, TypeLike},
+    Error,
+};
+use mlir_sys::{
+    mlirShapedTypeGetElementType, mlirShapedTypeGetRank, mlirSparseElementsAttrGetIndices,
+    mlirSparseElementsAttrGetValues, mlirSparseElementsAttribute, MlirAttribute,
+};
+
+/// A sparse elements attribute.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct SparseElementsAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SparseElementsAttribute<'c> {
+    /// Creates a sparse elements attribute.
+    ///
+    /// Indices must be 64-bit integers with as many coordinates per value as
+    /// the rank of a shaped type.
+    pub fn new(
+        r#type: Type<'c>,
+        indices: DenseElementsAttribute<'c>,
+        values: DenseElementsAttribute<'c>,
+    ) -> Result<Self, Error> {
+        if !r#type.is_shaped() {
+            return Err(Error::TypeExpected("shaped", r#type.to_string()));
+        }
+
+        let index_type =
+            unsafe { Type::from_raw(mlirShapedTypeGetElementType(indices.r#type().to_raw())) };
+
+        if !i64::is_element_type(index_type) {
+            return Err(Error::ElementExpected {
+                r#type: i64::name(),
+                value: indices.to_string(),
+            });
+        }
+
+        let rank = (unsafe { mlirShapedTypeGetRank(r#type.to_raw()) }) as usize;
+
+        if indices.len() != values.len() * rank.max(1) {
+            return Err(Error::ElementCountMismatch {
+                expected: values.len() * rank.max(1),
+                actual: indices.len(),
+            });
+        }
+
+        Ok(unsafe {
+            Self::from_raw(mlirSparseElementsAttribute(
+                r#type.to_raw(),
+                indices.to_raw(),
+                values.to_raw(),
+            ))
+        })
+    }
+
+    /// Returns indices of non-zero elements.
+    pub fn indices(&self) -> DenseElementsAttribute<'c> {
+        DenseElementsAttribute::try_from(unsafe {
+            Attribute::from_raw(mlirSparseElementsAttrGetIndices(self.to_raw()))
+        })
+        .unwrap()
+    }
+
+    /// Returns values of non-zero elements.
+    pub fn values(&self) -> DenseElementsAttribute<'c> {
+        DenseElementsAttribute::try_from(unsafe {
+            Attribute::from_raw(mlirSparseElementsAttrGetValues(self.to_raw()))
+        })
+        .unwrap()
+    }
+}
+
+attribute_traits!(
+    SparseElementsAttribute,
+    is_sparse_elements,
+    "sparse elements"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::r#type::{Dimension, IntegerType, RankedTensorType},
+        test::create_test_context,
+        Context,
+    };
+
+    fn create_attribute(context: &Context) -> SparseElementsAttribute {
+        let integer_type = IntegerType::new(context, 32).into();
+
+        SparseElementsAttribute::new(
+            RankedTensorType::new(
+                &[Dimension::Static(3), Dimension::Static(4)],
+                integer_type,
+                None,
+            )
+            .unwrap()
+            .into(),
+            DenseElementsAttribute::from_values(
+                RankedTensorType::new(
+                    &[Dimension::Static(2), Dimension::Static(2)],
+                    IntegerType::new(context, 64).into(),
+                    None,
+                )
+                .unwrap()
+                .into(),
+                &[0i64, 0, 1, 2],
+            )
+            .unwrap(),
+            DenseElementsAttribute::from_values(
+                RankedTensorType::new(&[Dimension::Static(2)], integer_type, None)
+                    .unwrap()
+                    .into(),
+                &[1i32, 5],
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(create_attribute(&context)),
+            Attribute::parse(
+                &context,
+                "sparse<[[0, 0], [1, 2]], [1, 5]> : tensor<3x4xi32>"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn new_with_invalid_indices() {
+        let context = create_test_context();
+        let attribute = create_attribute(&context);
+
+        assert!(matches!(
+            SparseElementsAttribute::new(
+                attribute.r#type(),
+                attribute.values(),
+                attribute.values()
+            ),
+            Err(Error::ElementExpected { r#type: "i64", .. })
+        ));
+        assert_eq!(
+            SparseElementsAttribute::new(
+                attribute.r#type(),
+                attribute.indices(),
+                DenseElementsAttribute::from_values(
+                    RankedTensorType::new(
+                        &[Dimension::Static(1)],
+                        IntegerType::new(&context, 32).into(),
+                        None
+                    )
+                    .unwrap()
+                    .into(),
+                    &[1i32],
+                )
+                .unwrap()
+            ),
+            Err(Error::ElementCountMismatch {
+                expected: 2,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn indices_and_values() {
+        let context = create_test_context();
+        let attribute = create_attribute(&context);
+
+        assert_eq!(
+            attribute
+                .indices()
+                .elements::<i64>()
+                .unwrap()
+                .collect::<Vec<_>>(),
+            [0, 0, 1, 2]
+        );
+        assert_eq!(
+            attribute
+                .values()
+                .elements::<i32>()
+                .unwrap()
+                .collect::<Vec<_>>(),
+            [1, 5]
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            SparseElementsAttribute::try_from(Attribute::parse(&context, "[42]").unwrap()),
+            Err(Error::AttributeExpected("sparse elements", _))
+        ));
+    }
+}
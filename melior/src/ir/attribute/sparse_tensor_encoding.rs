@@ -0,0 +1,407 @@
+use super::{Attribute, AttributeLike};
+use crate::{ir::AffineMap, Context, Error};
+use mlir_sys::{
+    mlirSparseTensorEncodingAttrGet, mlirSparseTensorEncodingAttrGetCrdWidth,
+    mlirSparseTensorEncodingAttrGetDimToLvl, mlirSparseTensorEncodingAttrGetLvlType,
+    mlirSparseTensorEncodingAttrGetPosWidth, mlirSparseTensorEncodingGetLvlRank, MlirAffineMap,
+    MlirAttribute, MlirSparseTensorDimLevelType,
+    MlirSparseTensorDimLevelType_MLIR_SPARSE_TENSOR_DIM_LEVEL_COMPRESSED as COMPRESSED,
+    MlirSparseTensorDimLevelType_MLIR_SPARSE_TENSOR_DIM_LEVEL_COMPRESSED_WITH_HI as COMPRESSED_WITH_HIGH,
+    MlirSparseTensorDimLevelType_MLIR_SPARSE_TENSOR_DIM_LEVEL_DENSE as DENSE,
+    MlirSparseTensorDimLevelType_MLIR_SPARSE_TENSOR_DIM_LEVEL_SINGLETON as SINGLETON,
+    MlirSparseTensorDimLevelType_MLIR_SPARSE_TENSOR_DIM_LEVEL_TWO_OUT_OF_FOUR as TWO_OUT_OF_FOUR,
+};
+use std::ptr::null;
+
+const NON_UNIQUE: MlirSparseTensorDimLevelType = 1;
+const NON_ORDERED: MlirSparseTensorDimLevelType = 2;
+
+/// A storage format of a sparse tensor level.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SparseTensorLevelFormat {
+    /// A dense level.
+    Dense,
+    /// A compressed level.
+    Compressed,
+    /// A singleton level.
+    Singleton,
+    /// A compressed level with high positions.
+    CompressedWithHigh,
+    /// A 2:4 structured sparsity level.
+    TwoOutOfFour,
+    /// A level of a format unknown to Melior, as a raw value without property
+    /// bits.
+    Other(u32),
+}
+
+/// A type of a sparse tensor level.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SparseTensorLevelType {
+    format: SparseTensorLevelFormat,
+    unique: bool,
+    ordered: bool,
+}
+
+impl SparseTensorLevelType {
+    /// Creates a level type.
+    ///
+    /// Dense and 2:4 levels are always unique and ordered. Property bits in raw
+    /// values of unknown formats are ignored.
+    pub fn new(format: SparseTensorLevelFormat, unique: bool, ordered: bool) -> Self {
+        let fixed = matches!(
+            format,
+            SparseTensorLevelFormat::Dense | SparseTensorLevelFormat::TwoOutOfFour
+        );
+
+        Self {
+            format: match format {
+                SparseTensorLevelFormat::Other(format) => {
+                    SparseTensorLevelFormat::Other(format & !(NON_UNIQUE | NON_ORDERED))
+                }
+                format => format,
+            },
+            unique: unique || fixed,
+            ordered: ordered || fixed,
+        }
+    }
+
+    /// Returns a format.
+    pub fn format(&self) -> SparseTensorLevelFormat {
+        self.format
+    }
+
+    /// Returns `true` if coordinates in a level are unique.
+    pub fn is_unique(&self) -> bool {
+        self.unique
+    }
+
+    /// Returns `true` if coordinates in a level are ordered.
+    pub fn is_ordered(&self) -> bool {
+        self.ordered
+    }
+
+    fn from_raw(raw: MlirSparseTensorDimLevelType) -> Self {
+        let format = match raw & !(NON_UNIQUE | NON_ORDERED) {
+            DENSE => SparseTensorLevelFormat::Dense,
+            COMPRESSED => SparseTensorLevelFormat::Compressed,
+            SINGLETON => SparseTensorLevelFormat::Singleton,
+            COMPRESSED_WITH_HIGH => SparseTensorLevelFormat::CompressedWithHigh,
+            TWO_OUT_OF_FOUR => SparseTensorLevelFormat::TwoOutOfFour,
+            format => SparseTensorLevelFormat::Other(format),
+        };
+
+        Self::new(format, raw & NON_UNIQUE == 0, raw & NON_ORDERED == 0)
+    }
+
+    fn to_raw(self) -> MlirSparseTensorDimLevelType {
+        (match self.format {
+            SparseTensorLevelFormat::Dense => DENSE,
+            SparseTensorLevelFormat::Compressed => COMPRESSED,
+            SparseTensorLevelFormat::Singleton => SINGLETON,
+            SparseTensorLevelFormat::CompressedWithHigh => COMPRESSED_WITH_HIGH,
+            SparseTensorLevelFormat::TwoOutOfFour => TWO_OUT_OF_FOUR,
+            SparseTensorLevelFormat::Other(format) => format,
+        }) | if self.unique { 0 } else { NON_UNIQUE }
+            | if self.ordered { 0 } else { NON_ORDERED }
+    }
+}
+
+impl From<SparseTensorLevelFormat> for SparseTensorLevelType {
+    fn from(format: SparseTensorLevelFormat) -> Self {
+        Self::new(format, true, true)
+    }
+}
+
+/// A sparse tensor encoding attribute.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct SparseTensorEncodingAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SparseTensorEncodingAttribute<'c> {
+    /// Creates a sparse tensor encoding attribute.
+    ///
+    /// An identity dimension-to-level map is used if none is given. Otherwise,
+    /// the map must have a result for each level. Bit widths must be 0, 8, 16,
+    /// 32, or 64, where 0 denotes the native index width.
+    pub fn new(
+        context: &'c Context,
+        level_types: &[SparseTensorLevelType],
+        dimension_to_level: Option<AffineMap<'c>>,
+        position_width: u32,
+        coordinate_width: u32,
+    ) -> Result<Self, Error> {
+        if let Some(map) = dimension_to_level {
+            if map.result_count() != level_types.len() {
+                return Err(Error::ElementCountMismatch {
+                    expected: map.result_count(),
+                    actual: level_types.len(),
+                });
+            }
+        }
+
+        for (name, width) in [
+            ("position", position_width),
+            ("coordinate", coordinate_width),
+        ] {
+            if !matches!(width, 0 | 8 | 16 | 32 | 64) {
+                return Err(Error::InvalidBitWidth { name, width });
+            }
+        }
+
+        let level_types = level_types
+            .iter()
+            .map(|r#type| r#type.to_raw())
+            .collect::<Vec<_>>();
+
+        Ok(unsafe {
+            Self::from_raw(mlirSparseTensorEncodingAttrGet(
+                context.to_raw(),
+                level_types.len() as isize,
+                level_types.as_ptr(),
+                dimension_to_level
+                    .map(|map| map.to_raw())
+                    .unwrap_or(MlirAffineMap { ptr: null() }),
+                position_width as i32,
+                coordinate_width as i32,
+            ))
+        })
+    }
+
+    /// Returns a level rank.
+    pub fn level_rank(&self) -> usize {
+        (unsafe { mlirSparseTensorEncodingGetLvlRank(self.to_raw()) }) as usize
+    }
+
+    /// Returns a level type.
+    pub fn level_type(&self, index: usize) -> Result<SparseTensorLevelType, Error> {
+        if index < self.level_rank() {
+            Ok(SparseTensorLevelType::from_raw(unsafe {
+                mlirSparseTensorEncodingAttrGetLvlType(self.to_raw(), index as isize)
+            }))
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "sparse tensor level",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns level types.
+    pub fn level_types(&self) -> impl Iterator<Item = SparseTensorLevelType> + '_ {
+        (0..self.level_rank()).map(|index| {
+            SparseTensorLevelType::from_raw(unsafe {
+                mlirSparseTensorEncodingAttrGetLvlType(self.to_raw(), index as isize)
+            })
+        })
+    }
+
+    /// Returns a dimension-to-level map.
+    ///
+    /// `None` denotes an identity map.
+    pub fn dimension_to_level(&self) -> Option<AffineMap<'c>> {
+        let raw = unsafe { mlirSparseTensorEncodingAttrGetDimToLvl(self.to_raw()) };
+
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { AffineMap::from_raw(raw) })
+        }
+    }
+
+    /// Returns a bit width of positions.
+    pub fn position_width(&self) -> u32 {
+        (unsafe { mlirSparseTensorEncodingAttrGetPosWidth(self.to_raw()) }) as u32
+    }
+
+    /// Returns a bit width of coordinates.
+    pub fn coordinate_width(&self) -> u32 {
+        (unsafe { mlirSparseTensorEncodingAttrGetCrdWidth(self.to_raw()) }) as u32
+    }
+}
+
+attribute_traits!(
+    SparseTensorEncodingAttribute,
+    is_sparse_tensor_encoding_attr,
+    "sparse tensor encoding"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::{
+            r#type::{Dimension, RankedTensorType},
+            Type,
+        },
+        test::create_test_context,
+    };
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(
+                SparseTensorEncodingAttribute::new(
+                    &context,
+                    &[
+                        SparseTensorLevelFormat::Dense.into(),
+                        SparseTensorLevelFormat::Compressed.into()
+                    ],
+                    None,
+                    0,
+                    0,
+                )
+                .unwrap()
+            ),
+            Attribute::parse(
+                &context,
+                "#sparse_tensor.encoding<{ lvlTypes = [ \"dense\", \"compressed\" ] }>"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn level_types() {
+        let context = create_test_context();
+        let level_types = [
+            SparseTensorLevelFormat::Dense.into(),
+            SparseTensorLevelType::new(SparseTensorLevelFormat::Compressed, false, true),
+            SparseTensorLevelType::new(SparseTensorLevelFormat::Singleton, true, false),
+        ];
+        let attribute =
+            SparseTensorEncodingAttribute::new(&context, &level_types, None, 0, 0).unwrap();
+
+        assert_eq!(attribute.level_rank(), 3);
+        assert_eq!(attribute.level_type(1), Ok(level_types[1]));
+        assert!(matches!(
+            attribute.level_type(3),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+        assert_eq!(attribute.level_types().collect::<Vec<_>>(), level_types);
+    }
+
+    #[test]
+    fn level_type_properties() {
+        let level_type = SparseTensorLevelType::new(SparseTensorLevelFormat::Dense, false, false);
+
+        assert!(level_type.is_unique());
+        assert!(level_type.is_ordered());
+    }
+
+    #[test]
+    fn level_type_raw() {
+        let level_type =
+            SparseTensorLevelType::new(SparseTensorLevelFormat::Other(128), false, true);
+
+        assert_eq!(
+            SparseTensorLevelType::from_raw(level_type.to_raw()),
+            level_type
+        );
+        assert_eq!(
+            SparseTensorLevelType::from_raw(COMPRESSED | NON_UNIQUE),
+            SparseTensorLevelType::new(SparseTensorLevelFormat::Compressed, false, true)
+        );
+    }
+
+    #[test]
+    fn dimension_to_level() {
+        let context = create_test_context();
+        let map = AffineMap::permutation(&context, &[1, 0]).unwrap();
+        let level_types = [SparseTensorLevelFormat::Compressed.into(); 2];
+
+        assert_eq!(
+            SparseTensorEncodingAttribute::new(&context, &level_types, Some(map), 0, 0)
+                .unwrap()
+                .dimension_to_level(),
+            Some(map)
+        );
+        assert_eq!(
+            SparseTensorEncodingAttribute::new(&context, &level_types, None, 0, 0)
+                .unwrap()
+                .dimension_to_level(),
+            None
+        );
+        assert_eq!(
+            SparseTensorEncodingAttribute::new(&context, &level_types[..1], Some(map), 0, 0),
+            Err(Error::ElementCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn widths() {
+        let context = create_test_context();
+        let attribute = SparseTensorEncodingAttribute::new(
+            &context,
+            &[SparseTensorLevelFormat::Compressed.into()],
+            None,
+            32,
+            8,
+        )
+        .unwrap();
+
+        assert_eq!(attribute.position_width(), 32);
+        assert_eq!(attribute.coordinate_width(), 8);
+    }
+
+    #[test]
+    fn invalid_widths() {
+        let context = create_test_context();
+        let level_types = [SparseTensorLevelFormat::Compressed.into()];
+
+        assert_eq!(
+            SparseTensorEncodingAttribute::new(&context, &level_types, None, 7, 0),
+            Err(Error::InvalidBitWidth {
+                name: "position",
+                width: 7
+            })
+        );
+        assert_eq!(
+            SparseTensorEncodingAttribute::new(&context, &level_types, None, 0, u32::MAX),
+            Err(Error::InvalidBitWidth {
+                name: "coordinate",
+                width: u32::MAX
+            })
+        );
+    }
+
+    #[test]
+    fn tensor_encoding() {
+        let context = create_test_context();
+        let encoding = SparseTensorEncodingAttribute::new(
+            &context,
+            &[SparseTensorLevelFormat::Compressed.into()],
+            None,
+            0,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            RankedTensorType::new(
+                &[Dimension::Static(42)],
+                Type::float64(&context),
+                Some(encoding.into()),
+            )
+            .unwrap()
+            .encoding(),
+            Some(encoding.into())
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            SparseTensorEncodingAttribute::try_from(Attribute::parse(&context, "[42]").unwrap()),
+            Err(Error::AttributeExpected("sparse tensor encoding", _))
+        ));
+    }
+}
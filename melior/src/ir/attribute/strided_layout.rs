@@ -0,0 +1,324 @@
+use super::{Attribute, AttributeLike};
+use crate::{
+    ir::{AffineExpr, AffineExprKind, AffineMap},
+    Context, Error,
+};
+use mlir_sys::{
+    mlirAffineMapGetContext, mlirAttributeGetContext, mlirIndexTypeGet, mlirMemRefTypeGet,
+    mlirMemRefTypeGetAffineMap, mlirShapedTypeGetDynamicSize,
+    mlirShapedTypeGetDynamicStrideOrOffset, mlirShapedTypeIsDynamicStrideOrOffset,
+    mlirStridedLayoutAttrGet, mlirStridedLayoutAttrGetNumStrides, mlirStridedLayoutAttrGetOffset,
+    mlirStridedLayoutAttrGetStride, MlirAttribute, MlirContext,
+};
+use std::ptr::null_mut;
+
+/// A strided layout attribute.
+///
+/// Dynamic offsets and strides are represented by `None`.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct StridedLayoutAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> StridedLayoutAttribute<'c> {
+    /// Creates a strided layout attribute.
+    pub fn new(context: &'c Context, offset: Option<i64>, strides: &[Option<i64>]) -> Self {
+        unsafe { Self::from_context_raw(context.to_raw(), offset, strides) }
+    }
+
+    unsafe fn from_context_raw(
+        context: MlirContext,
+        offset: Option<i64>,
+        strides: &[Option<i64>],
+    ) -> Self {
+        let strides = strides
+            .iter()
+            .map(|&stride| to_raw_stride(stride))
+            .collect::<Vec<_>>();
+
+        Self::from_raw(mlirStridedLayoutAttrGet(
+            context,
+            to_raw_stride(offset),
+            strides.len() as isize,
+            strides.as_ptr(),
+        ))
+    }
+
+    /// Creates a strided layout attribute from an affine map.
+    ///
+    /// The map must have a single result which is a sum of a (possibly
+    /// symbolic) offset and dimensions multiplied by (possibly symbolic)
+    /// strides. Maps with multiple results, such as permutations, are not
+    /// strided layouts.
+    pub fn from_affine_map(map: AffineMap<'c>) -> Result<Self, Error> {
+        let error = || Error::AttributeExpected("strided layout", map.to_string());
+
+        if map.result_count() != 1 {
+            return Err(error());
+        }
+
+        let mut offset: Option<i64> = Some(0);
+        let mut strides = vec![Some(0); map.dimension_count()];
+        let mut expressions = vec![map.result(0)?];
+
+        while let Some(expression) = expressions.pop() {
+            let (target, value) = match expression.kind() {
+                AffineExprKind::Add(lhs, rhs) => {
+                    expressions.extend([lhs, rhs]);
+                    continue;
+                }
+                AffineExprKind::Dimension(dimension) => (&mut strides[dimension], Some(1)),
+                AffineExprKind::Mul(lhs, rhs) => match (lhs.kind(), rhs.kind()) {
+                    (AffineExprKind::Dimension(dimension), _) => (
+                        &mut strides[dimension],
+                        constant_or_symbol(rhs).ok_or_else(error)?,
+                    ),
+                    (_, AffineExprKind::Dimension(dimension)) => (
+                        &mut strides[dimension],
+                        constant_or_symbol(lhs).ok_or_else(error)?,
+                    ),
+                    _ => {
+                        let lhs = constant_or_symbol(lhs).ok_or_else(error)?;
+                        let rhs = constant_or_symbol(rhs).ok_or_else(error)?;
+
+                        (
+                            &mut offset,
+                            match (lhs, rhs) {
+                                (Some(lhs), Some(rhs)) => {
+                                    Some(lhs.checked_mul(rhs).ok_or_else(error)?)
+                                }
+                                _ => None,
+                            },
+                        )
+                    }
+                },
+                _ => (
+                    &mut offset,
+                    constant_or_symbol(expression).ok_or_else(error)?,
+                ),
+            };
+
+            *target = match (*target, value) {
+                (Some(target), Some(value)) => Some(target.checked_add(value).ok_or_else(error)?),
+                _ => None,
+            };
+        }
+
+        Ok(unsafe {
+            Self::from_context_raw(mlirAffineMapGetContext(map.to_raw()), offset, &strides)
+        })
+    }
+
+    /// Returns an offset.
+    pub fn offset(&self) -> Option<i64> {
+        from_raw_stride(unsafe { mlirStridedLayoutAttrGetOffset(self.to_raw()) })
+    }
+
+    /// Returns a number of strides.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirStridedLayoutAttrGetNumStrides(self.to_raw()) }) as usize
+    }
+
+    /// Checks if strides are empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a stride.
+    pub fn stride(&self, index: usize) -> Result<Option<i64>, Error> {
+        if index < self.len() {
+            Ok(from_raw_stride(unsafe {
+                mlirStridedLayoutAttrGetStride(self.to_raw(), index as isize)
+            }))
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "stride",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns strides.
+    pub fn strides(&self) -> Vec<Option<i64>> {
+        (0..self.len())
+            .map(|index| {
+                from_raw_stride(unsafe {
+                    mlirStridedLayoutAttrGetStride(self.to_raw(), index as isize)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns an affine map.
+    ///
+    /// Dynamic offsets and strides are mapped to symbols in order.
+    pub fn affine_map(&self) -> AffineMap<'c> {
+        // The C API exposes layout maps only through mem-ref types.
+        let dimensions = vec![unsafe { mlirShapedTypeGetDynamicSize() }; self.len()];
+
+        unsafe {
+            AffineMap::from_raw(mlirMemRefTypeGetAffineMap(mlirMemRefTypeGet(
+                mlirIndexTypeGet(mlirAttributeGetContext(self.to_raw())),
+                dimensions.len() as isize,
+                dimensions.as_ptr(),
+                self.to_raw(),
+                MlirAttribute { ptr: null_mut() },
+            )))
+        }
+    }
+}
+
+attribute_traits!(StridedLayoutAttribute, is_strided_layout, "strided layout");
+
+fn constant_or_symbol(expression: AffineExpr) -> Option<Option<i64>> {
+    match expression.kind() {
+        AffineExprKind::Constant(value) => Some(Some(value)),
+        AffineExprKind::Symbol(_) => Some(None),
+        _ => None,
+    }
+}
+
+fn from_raw_stride(value: i64) -> Option<i64> {
+    if unsafe { mlirShapedTypeIsDynamicStrideOrOffset(value) } {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn to_raw_stride(value: Option<i64>) -> i64 {
+    value.unwrap_or_else(|| unsafe { mlirShapedTypeGetDynamicStrideOrOffset() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::attribute::AffineMapAttribute, test::create_test_context};
+
+    fn parse_map<'c>(context: &'c Context, source: &str) -> AffineMap<'c> {
+        AffineMapAttribute::try_from(
+            Attribute::parse(context, &format!("affine_map<{source}>")).unwrap(),
+        )
+        .unwrap()
+        .value()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(StridedLayoutAttribute::new(
+                &context,
+                Some(0),
+                &[Some(4), Some(1)]
+            )),
+            Attribute::parse(&context, "strided<[4, 1]>").unwrap()
+        );
+        assert_eq!(
+            Attribute::from(StridedLayoutAttribute::new(
+                &context,
+                None,
+                &[None, Some(1)]
+            )),
+            Attribute::parse(&context, "strided<[?, 1], offset: ?>").unwrap()
+        );
+    }
+
+    #[test]
+    fn offset() {
+        let context = create_test_context();
+
+        assert_eq!(
+            StridedLayoutAttribute::new(&context, Some(42), &[Some(1)]).offset(),
+            Some(42)
+        );
+        assert_eq!(
+            StridedLayoutAttribute::new(&context, None, &[Some(1)]).offset(),
+            None
+        );
+    }
+
+    #[test]
+    fn strides() {
+        let context = create_test_context();
+        let attribute = StridedLayoutAttribute::new(&context, Some(0), &[None, Some(3), Some(1)]);
+
+        assert_eq!(attribute.len(), 3);
+        assert!(!attribute.is_empty());
+        assert_eq!(attribute.stride(1), Ok(Some(3)));
+        assert!(matches!(
+            attribute.stride(3),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+        assert_eq!(attribute.strides(), vec![None, Some(3), Some(1)]);
+    }
+
+    #[test]
+    fn affine_map() {
+        let context = create_test_context();
+
+        assert_eq!(
+            StridedLayoutAttribute::new(&context, Some(0), &[Some(4), Some(1)]).affine_map(),
+            parse_map(&context, "(d0, d1) -> (d0 * 4 + d1)")
+        );
+    }
+
+    #[test]
+    fn from_affine_map() {
+        let context = create_test_context();
+
+        assert_eq!(
+            StridedLayoutAttribute::from_affine_map(parse_map(
+                &context,
+                "(d0, d1) -> (d0 * 4 + d1 + 2)"
+            )),
+            Ok(StridedLayoutAttribute::new(
+                &context,
+                Some(2),
+                &[Some(4), Some(1)]
+            ))
+        );
+        assert_eq!(
+            StridedLayoutAttribute::from_affine_map(parse_map(
+                &context,
+                "(d0, d1)[s0, s1] -> (d0 * s1 + d1 + s0 * 4)"
+            )),
+            Ok(StridedLayoutAttribute::new(
+                &context,
+                None,
+                &[None, Some(1)]
+            ))
+        );
+        assert!(matches!(
+            StridedLayoutAttribute::from_affine_map(parse_map(&context, "(d0) -> (d0 floordiv 2)")),
+            Err(Error::AttributeExpected("strided layout", _))
+        ));
+        assert!(matches!(
+            StridedLayoutAttribute::from_affine_map(parse_map(&context, "(d0, d1) -> (d1, d0)")),
+            Err(Error::AttributeExpected("strided layout", _))
+        ));
+    }
+
+    #[test]
+    fn affine_map_round_trip() {
+        let context = create_test_context();
+        let attribute = StridedLayoutAttribute::new(&context, None, &[None, Some(1)]);
+
+        assert_eq!(
+            StridedLayoutAttribute::from_affine_map(attribute.affine_map()),
+            Ok(attribute)
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            StridedLayoutAttribute::try_from(Attribute::parse(&context, "[42]").unwrap()),
+            Err(Error::AttributeExpected("strided layout", _))
+        ));
+    }
+}
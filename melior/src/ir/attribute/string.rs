@@ -3,7 +3,7 @@ use crate::{Context, Error, StringRef};
 use mlir_sys::{mlirStringAttrGet, mlirStringAttrGetValue, MlirAttribute};
 
 /// A string attribute.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 pub struct StringAttribute<'c> {
     attribute: Attribute<'c>,
 }
@@ -0,0 +1,151 @@
+use super::{Attribute, AttributeLike, FlatSymbolRefAttribute};
+use crate::{Context, Error, StringRef};
+use mlir_sys::{
+    mlirSymbolRefAttrGet, mlirSymbolRefAttrGetLeafReference, mlirSymbolRefAttrGetNestedReference,
+    mlirSymbolRefAttrGetNumNestedReferences, mlirSymbolRefAttrGetRootReference, MlirAttribute,
+};
+
+/// A symbol ref attribute.
+///
+/// Flat symbol ref attributes are also symbol ref attributes without nested
+/// references.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct SymbolRefAttribute<'c> {
+    attribute: Attribute<'c>,
+}
+
+impl<'c> SymbolRefAttribute<'c> {
+    /// Creates a symbol ref attribute.
+    pub fn new(
+        context: &'c Context,
+        root: &str,
+        nested_references: &[FlatSymbolRefAttribute<'c>],
+    ) -> Self {
+        let nested_references = nested_references
+            .iter()
+            .map(|reference| reference.to_raw())
+            .collect::<Vec<_>>();
+
+        unsafe {
+            Self::from_raw(mlirSymbolRefAttrGet(
+                context.to_raw(),
+                StringRef::new(root).to_raw(),
+                nested_references.len() as isize,
+                nested_references.as_ptr(),
+            ))
+        }
+    }
+
+    /// Returns a root reference.
+    pub fn root_reference(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetRootReference(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a leaf reference.
+    pub fn leaf_reference(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirSymbolRefAttrGetLeafReference(self.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns a number of nested references.
+    pub fn nested_reference_count(&self) -> usize {
+        (unsafe { mlirSymbolRefAttrGetNumNestedReferences(self.to_raw()) }) as usize
+    }
+
+    /// Returns a nested reference at a position.
+    pub fn nested_reference(&self, index: usize) -> Result<FlatSymbolRefAttribute<'c>, Error> {
+        if index < self.nested_reference_count() {
+            unsafe {
+                Attribute::from_raw(mlirSymbolRefAttrGetNestedReference(
+                    self.to_raw(),
+                    index as isize,
+                ))
+            }
+            .try_into()
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "nested reference",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns all nested references.
+    pub fn nested_references(&self) -> impl Iterator<Item = FlatSymbolRefAttribute<'c>> + '_ {
+        (0..self.nested_reference_count()).map(|index| {
+            self.nested_reference(index)
+                .expect("valid nested reference index")
+        })
+    }
+}
+
+attribute_traits!(SymbolRefAttribute, is_symbol_ref, "symbol ref");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    fn create_symbol_ref(context: &Context) -> SymbolRefAttribute {
+        SymbolRefAttribute::new(
+            context,
+            "foo",
+            &[
+                FlatSymbolRefAttribute::new(context, "bar"),
+                FlatSymbolRefAttribute::new(context, "baz"),
+            ],
+        )
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Attribute::from(create_symbol_ref(&context)),
+            Attribute::parse(&context, "@foo::@bar::@baz").unwrap()
+        );
+    }
+
+    #[test]
+    fn root_and_leaf_references() {
+        let context = create_test_context();
+        let attribute = create_symbol_ref(&context);
+
+        assert_eq!(attribute.root_reference(), "foo");
+        assert_eq!(attribute.leaf_reference(), "baz");
+    }
+
+    #[test]
+    fn nested_references() {
+        let context = create_test_context();
+        let attribute = create_symbol_ref(&context);
+
+        assert_eq!(attribute.nested_reference_count(), 2);
+        assert_eq!(
+            attribute
+                .nested_references()
+                .map(|reference| reference.value())
+                .collect::<Vec<_>>(),
+            ["bar", "baz"]
+        );
+        assert!(matches!(
+            attribute.nested_reference(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn flat() {
+        let context = create_test_context();
+        let attribute =
+            SymbolRefAttribute::try_from(Attribute::parse(&context, "@foo").unwrap()).unwrap();
+
+        assert_eq!(attribute.root_reference(), "foo");
+        assert_eq!(attribute.nested_reference_count(), 0);
+    }
+}
@@ -0,0 +1,345 @@
+use super::AffineExpr;
+use crate::{
+    context::{Context, ContextRef},
+    utility::print_callback,
+    Error,
+};
+use mlir_sys::{
+    mlirIntegerSetDump, mlirIntegerSetEmptyGet, mlirIntegerSetEqual, mlirIntegerSetGet,
+    mlirIntegerSetGetConstraint, mlirIntegerSetGetContext, mlirIntegerSetGetNumConstraints,
+    mlirIntegerSetGetNumDims, mlirIntegerSetGetNumEqualities, mlirIntegerSetGetNumInequalities,
+    mlirIntegerSetGetNumInputs, mlirIntegerSetGetNumSymbols, mlirIntegerSetIsCanonicalEmpty,
+    mlirIntegerSetIsConstraintEq, mlirIntegerSetPrint, mlirIntegerSetReplaceGet, MlirContext,
+    MlirIntegerSet,
+};
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+/// An integer set.
+#[derive(Clone, Copy)]
+pub struct IntegerSet<'c> {
+    raw: MlirIntegerSet,
+    _context: PhantomData<&'c Context>,
+}
+
+impl<'c> IntegerSet<'c> {
+    /// Creates an integer set.
+    ///
+    /// At least one constraint is required. Use [`IntegerSet::empty`] for an
+    /// empty set.
+    pub fn new(
+        context: &'c Context,
+        dimension_count: usize,
+        symbol_count: usize,
+        constraints: &[IntegerSetConstraint<'c>],
+    ) -> Result<Self, Error> {
+        if constraints.is_empty() {
+            return Err(Error::ConstraintExpected);
+        }
+
+        Ok(unsafe {
+            Self::from_context_raw(context.to_raw(), dimension_count, symbol_count, constraints)
+        })
+    }
+
+    pub(crate) unsafe fn from_context_raw(
+        context: MlirContext,
+        dimension_count: usize,
+        symbol_count: usize,
+        constraints: &[IntegerSetConstraint<'c>],
+    ) -> Self {
+        let (expressions, equalities): (Vec<_>, Vec<_>) = constraints
+            .iter()
+            .map(|constraint| match constraint {
+                IntegerSetConstraint::Equality(expression) => (expression.to_raw(), true),
+                IntegerSetConstraint::Inequality(expression) => (expression.to_raw(), false),
+            })
+            .unzip();
+
+        Self::from_raw(mlirIntegerSetGet(
+            context,
+            dimension_count as isize,
+            symbol_count as isize,
+            constraints.len() as isize,
+            expressions.as_ptr(),
+            equalities.as_ptr(),
+        ))
+    }
+
+    /// Creates a canonical empty integer set.
+    pub fn empty(context: &'c Context, dimension_count: usize, symbol_count: usize) -> Self {
+        unsafe {
+            Self::from_raw(mlirIntegerSetEmptyGet(
+                context.to_raw(),
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        }
+    }
+
+    /// Returns a context.
+    pub fn context(&self) -> ContextRef<'c> {
+        unsafe { ContextRef::from_raw(mlirIntegerSetGetContext(self.raw)) }
+    }
+
+    /// Returns `true` if an integer set is a canonical empty one.
+    pub fn is_empty(&self) -> bool {
+        unsafe { mlirIntegerSetIsCanonicalEmpty(self.raw) }
+    }
+
+    /// Returns a number of dimensions.
+    pub fn dimension_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumDims(self.raw) as usize }
+    }
+
+    /// Returns a number of symbols.
+    pub fn symbol_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumSymbols(self.raw) as usize }
+    }
+
+    /// Returns a number of inputs, which are dimensions and symbols.
+    pub fn input_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumInputs(self.raw) as usize }
+    }
+
+    /// Returns a number of constraints.
+    pub fn constraint_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumConstraints(self.raw) as usize }
+    }
+
+    /// Returns a number of equality constraints.
+    pub fn equality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumEqualities(self.raw) as usize }
+    }
+
+    /// Returns a number of inequality constraints.
+    pub fn inequality_count(&self) -> usize {
+        unsafe { mlirIntegerSetGetNumInequalities(self.raw) as usize }
+    }
+
+    /// Returns a constraint.
+    pub fn constraint(&self, index: usize) -> Result<IntegerSetConstraint<'c>, Error> {
+        if index < self.constraint_count() {
+            Ok(unsafe { self.constraint_unchecked(index) })
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "integer set constraint",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+
+    /// Returns constraints.
+    pub fn constraints(&self) -> impl Iterator<Item = IntegerSetConstraint<'c>> + '_ {
+        (0..self.constraint_count()).map(|index| unsafe { self.constraint_unchecked(index) })
+    }
+
+    /// Returns `true` if a constraint is an equality.
+    pub fn is_equality(&self, index: usize) -> Result<bool, Error> {
+        Ok(matches!(
+            self.constraint(index)?,
+            IntegerSetConstraint::Equality(_)
+        ))
+    }
+
+    /// Replaces dimensions and symbols with expressions.
+    pub fn replace(
+        &self,
+        dimensions: &[AffineExpr<'c>],
+        symbols: &[AffineExpr<'c>],
+        dimension_count: usize,
+        symbol_count: usize,
+    ) -> Result<Self, Error> {
+        if dimensions.len() != self.dimension_count() {
+            return Err(Error::ElementCountMismatch {
+                expected: self.dimension_count(),
+                actual: dimensions.len(),
+            });
+        } else if symbols.len() != self.symbol_count() {
+            return Err(Error::ElementCountMismatch {
+                expected: self.symbol_count(),
+                actual: symbols.len(),
+            });
+        }
+
+        Ok(unsafe {
+            Self::from_raw(mlirIntegerSetReplaceGet(
+                self.raw,
+                dimensions.as_ptr() as *const _,
+                symbols.as_ptr() as *const _,
+                dimension_count as isize,
+                symbol_count as isize,
+            ))
+        })
+    }
+
+    /// Dumps an integer set.
+    pub fn dump(&self) {
+        unsafe { mlirIntegerSetDump(self.raw) }
+    }
+
+    /// Creates an integer set from a raw object.
+    ///
+    /// # Safety
+    ///
+    /// A raw object must be valid.
+    pub unsafe fn from_raw(raw: MlirIntegerSet) -> Self {
+        Self {
+            raw,
+            _context: Default::default(),
+        }
+    }
+
+    /// Converts an integer set into a raw object.
+    pub fn to_raw(self) -> MlirIntegerSet {
+        self.raw
+    }
+
+    unsafe fn constraint_unchecked(&self, index: usize) -> IntegerSetConstraint<'c> {
+        let expression =
+            AffineExpr::from_raw(mlirIntegerSetGetConstraint(self.raw, index as isize));
+
+        if mlirIntegerSetIsConstraintEq(self.raw, index as isize) {
+            IntegerSetConstraint::Equality(expression)
+        } else {
+            IntegerSetConstraint::Inequality(expression)
+        }
+    }
+}
+
+impl<'c> PartialEq for IntegerSet<'c> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { mlirIntegerSetEqual(self.raw, other.raw) }
+    }
+}
+
+impl<'c> Eq for IntegerSet<'c> {}
+
+impl<'c> Display for IntegerSet<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let mut data = (formatter, Ok(()));
+
+        unsafe {
+            mlirIntegerSetPrint(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1
+    }
+}
+
+impl<'c> Debug for IntegerSet<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+/// A constraint of an integer set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IntegerSetConstraint<'c> {
+    /// A constraint of `expression == 0`.
+    Equality(AffineExpr<'c>),
+    /// A constraint of `expression >= 0`.
+    Inequality(AffineExpr<'c>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn create_set(context: &Context) -> IntegerSet {
+        let dimension = AffineExpr::dimension(context, 0);
+        let symbol = AffineExpr::symbol(context, 0);
+
+        IntegerSet::new(
+            context,
+            1,
+            1,
+            &[
+                IntegerSetConstraint::Inequality(dimension - 2),
+                IntegerSetConstraint::Equality(symbol - dimension),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            create_set(&context).to_string(),
+            "(d0)[s0] : (d0 - 2 >= 0, -d0 + s0 == 0)"
+        );
+        assert_eq!(
+            IntegerSet::new(&context, 1, 0, &[]),
+            Err(Error::ConstraintExpected)
+        );
+    }
+
+    #[test]
+    fn count() {
+        let context = Context::new();
+        let set = create_set(&context);
+
+        assert_eq!(set.dimension_count(), 1);
+        assert_eq!(set.symbol_count(), 1);
+        assert_eq!(set.input_count(), 2);
+        assert_eq!(set.constraint_count(), 2);
+        assert_eq!(set.equality_count(), 1);
+        assert_eq!(set.inequality_count(), 1);
+    }
+
+    #[test]
+    fn constraint() {
+        let context = Context::new();
+        let set = create_set(&context);
+
+        assert_eq!(
+            set.constraint(0).unwrap(),
+            IntegerSetConstraint::Inequality(AffineExpr::dimension(&context, 0) - 2)
+        );
+        assert_eq!(set.constraints().count(), 2);
+        assert!(!set.is_equality(0).unwrap());
+        assert!(set.is_equality(1).unwrap());
+        assert!(matches!(
+            set.constraint(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn empty() {
+        let context = Context::new();
+
+        assert!(IntegerSet::empty(&context, 1, 0).is_empty());
+        assert!(!create_set(&context).is_empty());
+    }
+
+    #[test]
+    fn replace() {
+        let context = Context::new();
+        let set = create_set(&context)
+            .replace(
+                &[AffineExpr::dimension(&context, 1)],
+                &[AffineExpr::constant(&context, 42)],
+                2,
+                0,
+            )
+            .unwrap();
+
+        assert_eq!(set.to_string(), "(d0, d1) : (d1 - 2 >= 0, -d1 + 42 == 0)");
+        assert!(matches!(
+            create_set(&context).replace(&[], &[], 0, 0),
+            Err(Error::ElementCountMismatch { .. })
+        ));
+    }
+}
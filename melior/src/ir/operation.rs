@@ -296,6 +296,11 @@ impl<'c> Operation<'c> {
         }
     }
 
+    /// Converts an operation into a raw object.
+    pub const fn to_raw(&self) -> MlirOperation {
+        self.raw
+    }
+
     /// Converts an operation into a raw object.
     pub fn into_raw(self) -> MlirOperation {
         let operation = self.raw;
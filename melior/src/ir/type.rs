@@ -2,26 +2,58 @@
 
 #[macro_use]
 mod r#macro;
+mod any_quantized;
+mod calibrated_quantized;
+mod complex;
+mod dimension;
+mod float;
 mod function;
 pub mod id;
 mod integer;
+mod kind;
 mod mem_ref;
+mod opaque;
+mod quantized_type_like;
 mod ranked_tensor;
 mod shaped_type_like;
 mod tuple;
 mod type_like;
+mod uniform_quantized;
+mod uniform_quantized_per_axis;
+mod unranked_mem_ref;
+mod unranked_tensor;
+mod vector;
 
 pub use self::{
-    function::FunctionType, id::TypeId, integer::IntegerType, mem_ref::MemRefType,
-    ranked_tensor::RankedTensorType, shaped_type_like::ShapedTypeLike, tuple::TupleType,
+    any_quantized::AnyQuantizedType,
+    calibrated_quantized::CalibratedQuantizedType,
+    complex::ComplexType,
+    dimension::Dimension,
+    float::{FloatKind, FloatType},
+    function::FunctionType,
+    id::TypeId,
+    integer::IntegerType,
+    kind::TypeKind,
+    mem_ref::{MemRefLayout, MemRefType},
+    opaque::OpaqueType,
+    quantized_type_like::QuantizedTypeLike,
+    ranked_tensor::RankedTensorType,
+    shaped_type_like::ShapedTypeLike,
+    tuple::TupleType,
     type_like::TypeLike,
+    uniform_quantized::UniformQuantizedType,
+    uniform_quantized_per_axis::UniformQuantizedPerAxisType,
+    unranked_mem_ref::UnrankedMemRefType,
+    unranked_tensor::UnrankedTensorType,
+    vector::VectorType,
 };
 use super::Location;
 use crate::{context::Context, string_ref::StringRef, utility::print_callback};
 use mlir_sys::{
-    mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet, mlirIndexTypeGet,
-    mlirNoneTypeGet, mlirTypeEqual, mlirTypeParseGet, mlirTypePrint, mlirVectorTypeGet,
-    mlirVectorTypeGetChecked, MlirType,
+    mlirBF16TypeGet, mlirF16TypeGet, mlirF32TypeGet, mlirF64TypeGet, mlirFloat8E4M3B11FNUZTypeGet,
+    mlirFloat8E4M3FNTypeGet, mlirFloat8E4M3FNUZTypeGet, mlirFloat8E5M2FNUZTypeGet,
+    mlirFloat8E5M2TypeGet, mlirIndexTypeGet, mlirNoneTypeGet, mlirTypeEqual, mlirTypeParseGet,
+    mlirTypePrint, mlirVectorTypeGet, mlirVectorTypeGetChecked, MlirType,
 };
 use std::{
     ffi::c_void,
@@ -68,6 +100,40 @@ impl<'c> Type<'c> {
         unsafe { Self::from_raw(mlirF64TypeGet(context.to_raw())) }
     }
 
+    /// Creates a float8 type with 4-bit exponent and 3-bit mantissa.
+    pub fn float8_e4m3fn(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3FNTypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 type with 4-bit exponent, 3-bit mantissa and unsigned
+    /// zero.
+    pub fn float8_e4m3fnuz(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 type with 4-bit exponent, 3-bit mantissa, exponent
+    /// bias of 11 and unsigned zero.
+    pub fn float8_e4m3b11fnuz(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E4M3B11FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 type with 5-bit exponent and 2-bit mantissa.
+    pub fn float8_e5m2(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E5M2TypeGet(context.to_raw())) }
+    }
+
+    /// Creates a float8 type with 5-bit exponent, 2-bit mantissa and unsigned
+    /// zero.
+    pub fn float8_e5m2fnuz(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(mlirFloat8E5M2FNUZTypeGet(context.to_raw())) }
+    }
+
+    /// Creates a tf32 type.
+    pub fn tf32(context: &'c Context) -> Self {
+        // TODO Use `mlirTF32TypeGet` when we upgrade to MLIR 18.
+        Self::parse(context, "tf32").unwrap()
+    }
+
     /// Creates an index type.
     pub fn index(context: &'c Context) -> Self {
         unsafe { Self::from_raw(mlirIndexTypeGet(context.to_raw())) }
@@ -105,6 +171,11 @@ impl<'c> Type<'c> {
         }
     }
 
+    /// Returns a kind.
+    pub fn kind(&self) -> TypeKind<'c> {
+        TypeKind::from(*self)
+    }
+
     /// Creates a type from a raw object.
     ///
     /// # Safety
@@ -171,11 +242,21 @@ impl<'c> Debug for Type<'c> {
 
 from_subtypes!(
     Type,
+    AnyQuantizedType,
+    CalibratedQuantizedType,
+    ComplexType,
+    FloatType,
     FunctionType,
     IntegerType,
     MemRefType,
+    OpaqueType,
     RankedTensorType,
-    TupleType
+    TupleType,
+    UniformQuantizedPerAxisType,
+    UniformQuantizedType,
+    UnrankedMemRefType,
+    UnrankedTensorType,
+    VectorType
 );
 
 #[cfg(test)]
@@ -200,6 +281,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn float8() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::float8_e4m3fn(&context),
+            Type::parse(&context, "f8E4M3FN").unwrap()
+        );
+        assert_eq!(
+            Type::float8_e4m3fnuz(&context),
+            Type::parse(&context, "f8E4M3FNUZ").unwrap()
+        );
+        assert_eq!(
+            Type::float8_e4m3b11fnuz(&context),
+            Type::parse(&context, "f8E4M3B11FNUZ").unwrap()
+        );
+        assert_eq!(
+            Type::float8_e5m2(&context),
+            Type::parse(&context, "f8E5M2").unwrap()
+        );
+        assert_eq!(
+            Type::float8_e5m2fnuz(&context),
+            Type::parse(&context, "f8E5M2FNUZ").unwrap()
+        );
+    }
+
+    #[test]
+    fn tf32() {
+        let context = create_test_context();
+
+        assert_eq!(Type::tf32(&context).to_string(), "tf32");
+    }
+
     #[test]
     fn index() {
         let context = create_test_context();
@@ -0,0 +1,75 @@
+use super::{quantized_type_like::quantized_type_flags, QuantizedTypeLike, TypeLike};
+use crate::{ir::Type, Error};
+use mlir_sys::{mlirAnyQuantizedTypeGet, MlirType};
+
+/// A quantized type with any quantization parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnyQuantizedType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> AnyQuantizedType<'c> {
+    /// Creates a quantized type with any quantization parameters.
+    pub fn new(
+        signed: bool,
+        storage_type: Type<'c>,
+        expressed_type: Type<'c>,
+        storage_min: i64,
+        storage_max: i64,
+    ) -> Self {
+        unsafe {
+            Self::from_raw(mlirAnyQuantizedTypeGet(
+                quantized_type_flags(signed),
+                storage_type.to_raw(),
+                expressed_type.to_raw(),
+                storage_min,
+                storage_max,
+            ))
+        }
+    }
+}
+
+impl<'c> QuantizedTypeLike<'c> for AnyQuantizedType<'c> {}
+
+type_traits!(AnyQuantizedType, is_any_quantized_type, "any quantized");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::r#type::IntegerType, test::create_test_context};
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::from(AnyQuantizedType::new(
+                true,
+                IntegerType::new(&context, 8).into(),
+                Type::float32(&context),
+                -128,
+                127
+            )),
+            Type::parse(&context, "!quant.any<i8:f32>").unwrap()
+        );
+    }
+
+    #[test]
+    fn storage() {
+        let context = create_test_context();
+        let r#type = AnyQuantizedType::new(
+            false,
+            IntegerType::new(&context, 8).into(),
+            Type::float32(&context),
+            0,
+            100,
+        );
+
+        assert_eq!(r#type.storage_type(), IntegerType::new(&context, 8).into());
+        assert_eq!(r#type.expressed_type(), Type::float32(&context));
+        assert!(!r#type.is_signed());
+        assert_eq!(r#type.storage_min(), 0);
+        assert_eq!(r#type.storage_max(), 100);
+        assert_eq!(r#type.storage_integral_width(), 8);
+    }
+}
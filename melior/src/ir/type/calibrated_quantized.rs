@@ -0,0 +1,78 @@
+use super::TypeLike;
+use crate::{ir::Type, Error};
+use mlir_sys::{
+    mlirCalibratedQuantizedTypeGet, mlirCalibratedQuantizedTypeGetMax,
+    mlirCalibratedQuantizedTypeGetMin, mlirQuantizedTypeGetExpressedType, MlirType,
+};
+
+/// A calibrated quantized type.
+///
+/// It has only an expressed type and no storage type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CalibratedQuantizedType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> CalibratedQuantizedType<'c> {
+    /// Creates a calibrated quantized type.
+    pub fn new(expressed_type: Type<'c>, min: f64, max: f64) -> Self {
+        unsafe {
+            Self::from_raw(mlirCalibratedQuantizedTypeGet(
+                expressed_type.to_raw(),
+                min,
+                max,
+            ))
+        }
+    }
+
+    /// Returns an expressed type.
+    pub fn expressed_type(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirQuantizedTypeGetExpressedType(self.r#type.to_raw())) }
+    }
+
+    /// Returns a minimum value.
+    pub fn min(&self) -> f64 {
+        unsafe { mlirCalibratedQuantizedTypeGetMin(self.r#type.to_raw()) }
+    }
+
+    /// Returns a maximum value.
+    pub fn max(&self) -> f64 {
+        unsafe { mlirCalibratedQuantizedTypeGetMax(self.r#type.to_raw()) }
+    }
+}
+
+type_traits!(
+    CalibratedQuantizedType,
+    is_calibrated_quantized_type,
+    "calibrated quantized"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::create_test_context;
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::from(CalibratedQuantizedType::new(
+                Type::float32(&context),
+                -1.0,
+                1.0
+            )),
+            Type::parse(&context, "!quant.calibrated<f32<-1.0:1.0>>").unwrap()
+        );
+    }
+
+    #[test]
+    fn parameters() {
+        let context = create_test_context();
+        let r#type = CalibratedQuantizedType::new(Type::float32(&context), -1.0, 1.0);
+
+        assert_eq!(r#type.expressed_type(), Type::float32(&context));
+        assert_eq!(r#type.min(), -1.0);
+        assert_eq!(r#type.max(), 1.0);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::Error;
+use mlir_sys::{mlirShapedTypeGetDynamicSize, mlirShapedTypeIsDynamicSize};
+use std::fmt::{self, Display, Formatter};
+
+/// A dimension of a shaped type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Dimension {
+    /// A static dimension, whose size must not exceed `i64::MAX`.
+    Static(u64),
+    /// A dynamic dimension.
+    Dynamic,
+}
+
+impl Dimension {
+    /// Returns `true` if a dimension is dynamic.
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, Self::Dynamic)
+    }
+
+    /// Returns `true` if a dimension is static.
+    pub fn is_static(&self) -> bool {
+        !self.is_dynamic()
+    }
+
+    /// Returns a size if a dimension is static.
+    pub fn size(&self) -> Option<u64> {
+        match self {
+            Self::Static(size) => Some(*size),
+            Self::Dynamic => None,
+        }
+    }
+
+    pub(crate) fn from_raw(size: i64) -> Self {
+        if unsafe { mlirShapedTypeIsDynamicSize(size) } {
+            Self::Dynamic
+        } else {
+            Self::Static(size as u64)
+        }
+    }
+
+    pub(crate) fn to_raw(self) -> Result<i64, Error> {
+        match self {
+            // Sizes above `i64::MAX` would collide with the dynamic size.
+            Self::Static(size) => i64::try_from(size).map_err(|_| Error::InvalidDimension(size)),
+            Self::Dynamic => Ok(unsafe { mlirShapedTypeGetDynamicSize() }),
+        }
+    }
+}
+
+impl From<u64> for Dimension {
+    fn from(size: u64) -> Self {
+        Self::Static(size)
+    }
+}
+
+impl Display for Dimension {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Static(size) => write!(formatter, "{size}"),
+            Self::Dynamic => write!(formatter, "?"),
+        }
+    }
+}
+
+pub(crate) fn to_raw_dimensions(dimensions: &[Dimension]) -> Result<Vec<i64>, Error> {
+    dimensions
+        .iter()
+        .map(|dimension| dimension.to_raw())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw() {
+        assert_eq!(Dimension::from_raw(42), Dimension::Static(42));
+        assert_eq!(
+            Dimension::from_raw(Dimension::Dynamic.to_raw().unwrap()),
+            Dimension::Dynamic
+        );
+        assert_eq!(Dimension::Static(i64::MAX as u64).to_raw(), Ok(i64::MAX));
+        assert_eq!(
+            Dimension::Static(1 << 63).to_raw(),
+            Err(Error::InvalidDimension(1 << 63))
+        );
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(Dimension::Static(42).size(), Some(42));
+        assert_eq!(Dimension::Dynamic.size(), None);
+        assert!(Dimension::Dynamic.is_dynamic());
+        assert!(Dimension::from(42).is_static());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Dimension::Static(42).to_string(), "42");
+        assert_eq!(Dimension::Dynamic.to_string(), "?");
+    }
+}
@@ -0,0 +1,162 @@
+use super::TypeLike;
+use crate::{ir::Type, Error, StringRef};
+use mlir_sys::{mlirTypeEqual, mlirTypeGetContext, mlirTypeParseGet, MlirType};
+
+/// A float type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FloatType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> FloatType<'c> {
+    /// Returns a kind.
+    pub fn kind(&self) -> FloatKind {
+        float_kind(self.r#type).unwrap()
+    }
+
+    /// Returns a bit width.
+    pub fn width(&self) -> usize {
+        self.kind().width()
+    }
+}
+
+type_traits!(FloatType, is_float, "float");
+
+/// A kind of a float type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FloatKind {
+    /// A bfloat16 type.
+    BFloat16,
+    /// A float16 type.
+    Float16,
+    /// A tf32 type.
+    TF32,
+    /// A float32 type.
+    Float32,
+    /// A float64 type.
+    Float64,
+    /// A float8 type with 4-bit exponent and 3-bit mantissa.
+    Float8E4M3FN,
+    /// A float8 type with 4-bit exponent, 3-bit mantissa and unsigned zero.
+    Float8E4M3FNUZ,
+    /// A float8 type with 4-bit exponent, 3-bit mantissa, exponent bias of 11
+    /// and unsigned zero.
+    Float8E4M3B11FNUZ,
+    /// A float8 type with 5-bit exponent and 2-bit mantissa.
+    Float8E5M2,
+    /// A float8 type with 5-bit exponent, 2-bit mantissa and unsigned zero.
+    Float8E5M2FNUZ,
+}
+
+impl FloatKind {
+    /// Returns a bit width.
+    pub fn width(&self) -> usize {
+        match self {
+            Self::Float8E4M3FN
+            | Self::Float8E4M3FNUZ
+            | Self::Float8E4M3B11FNUZ
+            | Self::Float8E5M2
+            | Self::Float8E5M2FNUZ => 8,
+            Self::BFloat16 | Self::Float16 => 16,
+            Self::TF32 => 19,
+            Self::Float32 => 32,
+            Self::Float64 => 64,
+        }
+    }
+}
+
+pub(super) fn float_kind(r#type: Type) -> Option<FloatKind> {
+    Some(if r#type.is_bf16() {
+        FloatKind::BFloat16
+    } else if r#type.is_f16() {
+        FloatKind::Float16
+    } else if r#type.is_f32() {
+        FloatKind::Float32
+    } else if r#type.is_f64() {
+        FloatKind::Float64
+    } else if r#type.is_float8e4m3fn() {
+        FloatKind::Float8E4M3FN
+    } else if r#type.is_float8e4m3fnuz() {
+        FloatKind::Float8E4M3FNUZ
+    } else if r#type.is_float8e4m3b11fnuz() {
+        FloatKind::Float8E4M3B11FNUZ
+    } else if r#type.is_float8e5m2() {
+        FloatKind::Float8E5M2
+    } else if r#type.is_float8e5m2fnuz() {
+        FloatKind::Float8E5M2FNUZ
+    } else if is_tf32(r#type) {
+        FloatKind::TF32
+    } else {
+        return None;
+    })
+}
+
+fn is_tf32(r#type: Type) -> bool {
+    // TODO Use `mlirTypeIsATF32` when we upgrade to MLIR 18.
+    unsafe {
+        mlirTypeEqual(
+            r#type.to_raw(),
+            mlirTypeParseGet(
+                mlirTypeGetContext(r#type.to_raw()),
+                StringRef::new("tf32").to_raw(),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn kind() {
+        let context = Context::new();
+
+        for (source, kind) in [
+            ("bf16", FloatKind::BFloat16),
+            ("f16", FloatKind::Float16),
+            ("tf32", FloatKind::TF32),
+            ("f32", FloatKind::Float32),
+            ("f64", FloatKind::Float64),
+            ("f8E4M3FN", FloatKind::Float8E4M3FN),
+            ("f8E4M3FNUZ", FloatKind::Float8E4M3FNUZ),
+            ("f8E4M3B11FNUZ", FloatKind::Float8E4M3B11FNUZ),
+            ("f8E5M2", FloatKind::Float8E5M2),
+            ("f8E5M2FNUZ", FloatKind::Float8E5M2FNUZ),
+        ] {
+            assert_eq!(
+                FloatType::try_from(Type::parse(&context, source).unwrap())
+                    .unwrap()
+                    .kind(),
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn width() {
+        let context = Context::new();
+
+        assert_eq!(
+            FloatType::try_from(Type::tf32(&context)).unwrap().width(),
+            19
+        );
+        assert_eq!(
+            FloatType::try_from(Type::float64(&context))
+                .unwrap()
+                .width(),
+            64
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(matches!(
+            FloatType::try_from(Type::index(&context)),
+            Err(Error::TypeExpected("float", _))
+        ));
+    }
+}
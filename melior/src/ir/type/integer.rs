@@ -7,7 +7,7 @@ use mlir_sys::{
 };
 
 /// A integer type.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct IntegerType<'c> {
     r#type: Type<'c>,
 }
@@ -0,0 +1,191 @@
+use super::{
+    ComplexType, FloatType, FunctionType, IntegerType, MemRefType, OpaqueType, RankedTensorType,
+    TupleType, Type, TypeLike, UnrankedMemRefType, UnrankedTensorType, VectorType,
+};
+
+/// A kind of a builtin type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TypeKind<'c> {
+    /// A complex type.
+    Complex(ComplexType<'c>),
+    /// A float type.
+    Float(FloatType<'c>),
+    /// A function type.
+    Function(FunctionType<'c>),
+    /// An index type.
+    Index,
+    /// An integer type.
+    Integer(IntegerType<'c>),
+    /// A memref type.
+    MemRef(MemRefType<'c>),
+    /// A none type.
+    None,
+    /// An opaque type.
+    Opaque(OpaqueType<'c>),
+    /// A ranked tensor type.
+    RankedTensor(RankedTensorType<'c>),
+    /// A tuple type.
+    Tuple(TupleType<'c>),
+    /// An unranked memref type.
+    UnrankedMemRef(UnrankedMemRefType<'c>),
+    /// An unranked tensor type.
+    UnrankedTensor(UnrankedTensorType<'c>),
+    /// A vector type.
+    Vector(VectorType<'c>),
+    /// A type of any other kind.
+    Other(Type<'c>),
+}
+
+impl<'c> From<Type<'c>> for TypeKind<'c> {
+    fn from(r#type: Type<'c>) -> Self {
+        if r#type.is_index() {
+            Self::Index
+        } else if r#type.is_none() {
+            Self::None
+        } else if let Ok(r#type) = ComplexType::try_from(r#type) {
+            Self::Complex(r#type)
+        } else if let Ok(r#type) = FunctionType::try_from(r#type) {
+            Self::Function(r#type)
+        } else if let Ok(r#type) = IntegerType::try_from(r#type) {
+            Self::Integer(r#type)
+        } else if let Ok(r#type) = MemRefType::try_from(r#type) {
+            Self::MemRef(r#type)
+        } else if let Ok(r#type) = OpaqueType::try_from(r#type) {
+            Self::Opaque(r#type)
+        } else if let Ok(r#type) = RankedTensorType::try_from(r#type) {
+            Self::RankedTensor(r#type)
+        } else if let Ok(r#type) = TupleType::try_from(r#type) {
+            Self::Tuple(r#type)
+        } else if let Ok(r#type) = UnrankedMemRefType::try_from(r#type) {
+            Self::UnrankedMemRef(r#type)
+        } else if let Ok(r#type) = UnrankedTensorType::try_from(r#type) {
+            Self::UnrankedTensor(r#type)
+        } else if let Ok(r#type) = VectorType::try_from(r#type) {
+            Self::Vector(r#type)
+        } else if let Ok(r#type) = FloatType::try_from(r#type) {
+            // Float types are checked last as checking tf32 parses a type.
+            Self::Float(r#type)
+        } else {
+            Self::Other(r#type)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::create_test_context, Context};
+
+    fn parse_kind<'c>(context: &'c Context, source: &str) -> TypeKind<'c> {
+        Type::parse(context, source).unwrap().kind()
+    }
+
+    #[test]
+    fn float() {
+        let context = Context::new();
+
+        for source in ["bf16", "tf32", "f64", "f8E5M2FNUZ"] {
+            let r#type = Type::parse(&context, source).unwrap();
+
+            assert_eq!(
+                r#type.kind(),
+                TypeKind::Float(FloatType::try_from(r#type).unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn index_and_none() {
+        let context = Context::new();
+
+        assert_eq!(Type::index(&context).kind(), TypeKind::Index);
+        assert_eq!(Type::none(&context).kind(), TypeKind::None);
+    }
+
+    #[test]
+    fn integer() {
+        let context = Context::new();
+
+        assert_eq!(
+            parse_kind(&context, "i42"),
+            TypeKind::Integer(IntegerType::new(&context, 42))
+        );
+    }
+
+    #[test]
+    fn function() {
+        let context = Context::new();
+
+        assert_eq!(
+            parse_kind(&context, "() -> ()"),
+            TypeKind::Function(FunctionType::new(&context, &[], &[]))
+        );
+    }
+
+    #[test]
+    fn tuple() {
+        let context = Context::new();
+
+        assert_eq!(
+            parse_kind(&context, "tuple<>"),
+            TypeKind::Tuple(TupleType::new(&context, &[]))
+        );
+    }
+
+    #[test]
+    fn shaped() {
+        let context = Context::new();
+
+        assert!(matches!(
+            parse_kind(&context, "tensor<42xf32>"),
+            TypeKind::RankedTensor(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "tensor<*xf32>"),
+            TypeKind::UnrankedTensor(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "memref<42xf32>"),
+            TypeKind::MemRef(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "memref<*xf32>"),
+            TypeKind::UnrankedMemRef(_)
+        ));
+        assert!(matches!(
+            parse_kind(&context, "vector<42xf32>"),
+            TypeKind::Vector(_)
+        ));
+    }
+
+    #[test]
+    fn complex() {
+        let context = Context::new();
+
+        assert!(matches!(
+            parse_kind(&context, "complex<f32>"),
+            TypeKind::Complex(_)
+        ));
+    }
+
+    #[test]
+    fn opaque() {
+        let context = Context::new();
+        context.set_allow_unregistered_dialects(true);
+
+        assert!(matches!(
+            parse_kind(&context, "!foo.bar"),
+            TypeKind::Opaque(_)
+        ));
+    }
+
+    #[test]
+    fn other() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            parse_kind(&context, "!llvm.ptr"),
+            TypeKind::Other(_)
+        ));
+    }
+}
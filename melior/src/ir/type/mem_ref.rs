@@ -1,6 +1,10 @@
-use super::{shaped_type_like::ShapedTypeLike, TypeLike};
+use super::{dimension::to_raw_dimensions, shaped_type_like::ShapedTypeLike, Dimension, TypeLike};
 use crate::{
-    ir::{affine_map::AffineMap, attribute::AttributeLike, Attribute, Location, Type},
+    ir::{
+        affine_map::AffineMap,
+        attribute::{AffineMapAttribute, AttributeLike, StridedLayoutAttribute},
+        Attribute, Location, Type,
+    },
     Error,
 };
 use mlir_sys::{
@@ -9,7 +13,7 @@ use mlir_sys::{
 };
 
 /// A mem-ref type.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct MemRefType<'c> {
     r#type: Type<'c>,
 }
@@ -18,36 +22,46 @@ impl<'c> MemRefType<'c> {
     /// Creates a mem-ref type.
     pub fn new(
         r#type: Type<'c>,
-        dimensions: &[i64],
-        layout: Option<Attribute<'c>>,
+        dimensions: &[Dimension],
+        layout: Option<MemRefLayout<'c>>,
         memory_space: Option<Attribute<'c>>,
-    ) -> Self {
-        unsafe {
+    ) -> Result<Self, Error> {
+        let dimensions = to_raw_dimensions(dimensions)?;
+
+        Ok(unsafe {
             Self::from_raw(mlirMemRefTypeGet(
                 r#type.to_raw(),
                 dimensions.len() as _,
-                dimensions.as_ptr() as *const _,
-                layout.unwrap_or_else(|| Attribute::null()).to_raw(),
+                dimensions.as_ptr(),
+                layout
+                    .map(Attribute::from)
+                    .unwrap_or_else(|| Attribute::null())
+                    .to_raw(),
                 memory_space.unwrap_or_else(|| Attribute::null()).to_raw(),
             ))
-        }
+        })
     }
 
     /// Creates a mem-ref type with diagnostics.
     pub fn checked(
         location: Location<'c>,
         r#type: Type<'c>,
-        dimensions: &[u64],
-        layout: Attribute<'c>,
+        dimensions: &[Dimension],
+        layout: Option<MemRefLayout<'c>>,
         memory_space: Attribute<'c>,
     ) -> Option<Self> {
+        let dimensions = to_raw_dimensions(dimensions).ok()?;
+
         unsafe {
             Self::from_option_raw(mlirMemRefTypeGetChecked(
                 location.to_raw(),
                 r#type.to_raw(),
                 dimensions.len() as isize,
-                dimensions.as_ptr() as *const i64,
-                layout.to_raw(),
+                dimensions.as_ptr(),
+                layout
+                    .map(Attribute::from)
+                    .unwrap_or_else(|| Attribute::null())
+                    .to_raw(),
                 memory_space.to_raw(),
             ))
         }
@@ -68,6 +82,44 @@ impl<'c> MemRefType<'c> {
         unsafe { Attribute::from_option_raw(mlirMemRefTypeGetMemorySpace(self.r#type.to_raw())) }
     }
 
+    /// Returns strides and an offset.
+    ///
+    /// Dynamic strides and offsets are `None`. Only strided, identity, and
+    /// single-result strided affine map layouts are supported. Like MLIR,
+    /// this rejects layouts with multiple results, such as permutations, as
+    /// they are not strided.
+    pub fn strides_and_offset(&self) -> Result<(Vec<Option<i64>>, Option<i64>), Error> {
+        if let Ok(layout) = StridedLayoutAttribute::try_from(self.layout()) {
+            Ok((layout.strides(), layout.offset()))
+        } else if self.affine_map().is_identity() {
+            let mut stride: Option<i64> = Some(1);
+            let mut strides = Vec::with_capacity(self.rank());
+
+            for dimension in self.shape().into_iter().rev() {
+                strides.push(stride);
+                stride = match (stride, dimension.size()) {
+                    (Some(stride), Some(size)) => Some(
+                        i64::try_from(size)
+                            .ok()
+                            .and_then(|size| stride.checked_mul(size))
+                            .ok_or_else(|| {
+                                Error::AttributeExpected("strided layout", self.to_string())
+                            })?,
+                    ),
+                    _ => None,
+                };
+            }
+
+            strides.reverse();
+
+            Ok((strides, Some(0)))
+        } else {
+            let layout = StridedLayoutAttribute::from_affine_map(self.affine_map())?;
+
+            Ok((layout.strides(), layout.offset()))
+        }
+    }
+
     unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
         if raw.ptr.is_null() {
             None
@@ -79,6 +131,42 @@ impl<'c> MemRefType<'c> {
 
 impl<'c> ShapedTypeLike<'c> for MemRefType<'c> {}
 
+/// A layout of a mem-ref type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MemRefLayout<'c> {
+    /// An affine map layout.
+    AffineMap(AffineMapAttribute<'c>),
+    /// A strided layout.
+    Strided(StridedLayoutAttribute<'c>),
+}
+
+impl<'c> From<AffineMap<'c>> for MemRefLayout<'c> {
+    fn from(map: AffineMap<'c>) -> Self {
+        Self::AffineMap(AffineMapAttribute::new(map))
+    }
+}
+
+impl<'c> From<AffineMapAttribute<'c>> for MemRefLayout<'c> {
+    fn from(attribute: AffineMapAttribute<'c>) -> Self {
+        Self::AffineMap(attribute)
+    }
+}
+
+impl<'c> From<StridedLayoutAttribute<'c>> for MemRefLayout<'c> {
+    fn from(attribute: StridedLayoutAttribute<'c>) -> Self {
+        Self::Strided(attribute)
+    }
+}
+
+impl<'c> From<MemRefLayout<'c>> for Attribute<'c> {
+    fn from(layout: MemRefLayout<'c>) -> Self {
+        match layout {
+            MemRefLayout::AffineMap(attribute) => attribute.into(),
+            MemRefLayout::Strided(attribute) => attribute.into(),
+        }
+    }
+}
+
 type_traits!(MemRefType, is_mem_ref, "mem ref");
 
 #[cfg(test)]
@@ -91,7 +179,15 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            Type::from(MemRefType::new(Type::float64(&context), &[42], None, None,)),
+            Type::from(
+                MemRefType::new(
+                    Type::float64(&context),
+                    &[Dimension::Static(42)],
+                    None,
+                    None,
+                )
+                .unwrap()
+            ),
             Type::parse(&context, "memref<42xf64>").unwrap()
         );
     }
@@ -101,12 +197,10 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            Type::from(MemRefType::new(
-                Type::float64(&context),
-                &[i64::MIN],
-                None,
-                None,
-            )),
+            Type::from(
+                MemRefType::new(Type::float64(&context), &[Dimension::Dynamic], None, None)
+                    .unwrap()
+            ),
             Type::parse(&context, "memref<?xf64>").unwrap()
         );
     }
@@ -116,7 +210,14 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[42, 42], None, None,).layout(),
+            MemRefType::new(
+                Type::index(&context),
+                &[Dimension::Static(42), Dimension::Static(42)],
+                None,
+                None,
+            )
+            .unwrap()
+            .layout(),
             Attribute::parse(&context, "affine_map<(d0, d1) -> (d0, d1)>").unwrap(),
         );
     }
@@ -126,9 +227,15 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[42, 42], None, None,)
-                .affine_map()
-                .to_string(),
+            MemRefType::new(
+                Type::index(&context),
+                &[Dimension::Static(42), Dimension::Static(42)],
+                None,
+                None,
+            )
+            .unwrap()
+            .affine_map()
+            .to_string(),
             "(d0, d1) -> (d0, d1)"
         );
     }
@@ -138,8 +245,144 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[42, 42], None, None).memory_space(),
+            MemRefType::new(
+                Type::index(&context),
+                &[Dimension::Static(42), Dimension::Static(42)],
+                None,
+                None
+            )
+            .unwrap()
+            .memory_space(),
             None,
         );
     }
+
+    #[test]
+    fn strides_and_offset() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::new(
+                Type::index(&context),
+                &[
+                    Dimension::Static(2),
+                    Dimension::Static(3),
+                    Dimension::Static(4)
+                ],
+                None,
+                None
+            )
+            .unwrap()
+            .strides_and_offset(),
+            Ok((vec![Some(12), Some(4), Some(1)], Some(0)))
+        );
+        assert_eq!(
+            MemRefType::new(
+                Type::index(&context),
+                &[
+                    Dimension::Static(2),
+                    Dimension::Dynamic,
+                    Dimension::Static(4)
+                ],
+                None,
+                None
+            )
+            .unwrap()
+            .strides_and_offset(),
+            Ok((vec![None, Some(4), Some(1)], Some(0)))
+        );
+        assert!(matches!(
+            MemRefType::new(
+                Type::index(&context),
+                &[
+                    Dimension::Static(2),
+                    Dimension::Static(1 << 32),
+                    Dimension::Static(1 << 32)
+                ],
+                None,
+                None
+            )
+            .unwrap()
+            .strides_and_offset(),
+            Err(Error::AttributeExpected("strided layout", _))
+        ));
+    }
+
+    #[test]
+    fn new_with_strided_layout() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(
+                MemRefType::new(
+                    Type::float32(&context),
+                    &[Dimension::Static(2), Dimension::Dynamic],
+                    Some(StridedLayoutAttribute::new(&context, None, &[None, Some(1)]).into()),
+                    None,
+                )
+                .unwrap()
+            ),
+            Type::parse(&context, "memref<2x?xf32, strided<[?, 1], offset: ?>>").unwrap()
+        );
+    }
+
+    #[test]
+    fn new_with_affine_map_layout() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(
+                MemRefType::new(
+                    Type::float32(&context),
+                    &[Dimension::Static(2), Dimension::Static(3)],
+                    Some(AffineMap::permutation(&context, &[1, 0]).unwrap().into()),
+                    None,
+                )
+                .unwrap()
+            ),
+            Type::parse(
+                &context,
+                "memref<2x3xf32, affine_map<(d0, d1) -> (d1, d0)>>"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn strides_and_offset_with_strided_layout() {
+        let context = Context::new();
+
+        assert_eq!(
+            MemRefType::try_from(
+                Type::parse(&context, "memref<2x3xf32, strided<[6, 1], offset: ?>>").unwrap()
+            )
+            .unwrap()
+            .strides_and_offset(),
+            Ok((vec![Some(6), Some(1)], None))
+        );
+        assert_eq!(
+            MemRefType::try_from(
+                Type::parse(
+                    &context,
+                    "memref<2x3xf32, affine_map<(d0, d1) -> (d0 * 4 + d1 + 2)>>"
+                )
+                .unwrap()
+            )
+            .unwrap()
+            .strides_and_offset(),
+            Ok((vec![Some(4), Some(1)], Some(2)))
+        );
+        assert!(matches!(
+            MemRefType::try_from(
+                Type::parse(
+                    &context,
+                    "memref<2x3xf32, affine_map<(d0, d1) -> (d1, d0)>>"
+                )
+                .unwrap()
+            )
+            .unwrap()
+            .strides_and_offset(),
+            Err(Error::AttributeExpected("strided layout", _))
+        ));
+    }
 }
@@ -0,0 +1,70 @@
+use super::TypeLike;
+use crate::{ir::Type, Context, Error, StringRef};
+use mlir_sys::{
+    mlirOpaqueTypeGet, mlirOpaqueTypeGetData, mlirOpaqueTypeGetDialectNamespace, MlirType,
+};
+
+/// An opaque type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OpaqueType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> OpaqueType<'c> {
+    /// Creates an opaque type.
+    pub fn new(context: &'c Context, namespace: &str, data: &str) -> Self {
+        unsafe {
+            Self::from_raw(mlirOpaqueTypeGet(
+                context.to_raw(),
+                StringRef::new(namespace).to_raw(),
+                StringRef::new(data).to_raw(),
+            ))
+        }
+    }
+
+    /// Returns a dialect namespace.
+    pub fn namespace(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetDialectNamespace(self.r#type.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+
+    /// Returns data.
+    pub fn data(&self) -> &'c str {
+        unsafe { StringRef::from_raw(mlirOpaqueTypeGetData(self.r#type.to_raw())) }
+            .as_str()
+            .unwrap()
+    }
+}
+
+type_traits!(OpaqueType, is_opaque, "opaque");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+        context.set_allow_unregistered_dialects(true);
+
+        assert_eq!(
+            Type::from(OpaqueType::new(&context, "foo", "bar")),
+            Type::parse(&context, "!foo.bar").unwrap()
+        );
+    }
+
+    #[test]
+    fn namespace() {
+        let context = Context::new();
+
+        assert_eq!(OpaqueType::new(&context, "foo", "bar").namespace(), "foo");
+    }
+
+    #[test]
+    fn data() {
+        let context = Context::new();
+
+        assert_eq!(OpaqueType::new(&context, "foo", "bar").data(), "bar");
+    }
+}
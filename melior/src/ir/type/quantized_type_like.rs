@@ -0,0 +1,149 @@
+use super::{IntegerType, Type, TypeLike};
+use crate::Error;
+use mlir_sys::{
+    mlirQuantizedTypeCastExpressedToStorageType, mlirQuantizedTypeCastFromExpressedType,
+    mlirQuantizedTypeCastFromStorageType, mlirQuantizedTypeCastToExpressedType,
+    mlirQuantizedTypeCastToStorageType, mlirQuantizedTypeGetExpressedType,
+    mlirQuantizedTypeGetSignedFlag, mlirQuantizedTypeGetStorageType,
+    mlirQuantizedTypeGetStorageTypeIntegralWidth, mlirQuantizedTypeGetStorageTypeMax,
+    mlirQuantizedTypeGetStorageTypeMin, mlirQuantizedTypeIsCompatibleExpressedType,
+    mlirQuantizedTypeIsSigned,
+};
+
+/// Trait for quantized types.
+pub trait QuantizedTypeLike<'c>: TypeLike<'c> {
+    /// Returns a storage type.
+    fn storage_type(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirQuantizedTypeGetStorageType(self.to_raw())) }
+    }
+
+    /// Returns an expressed type.
+    fn expressed_type(&self) -> Type<'c> {
+        unsafe { Type::from_raw(mlirQuantizedTypeGetExpressedType(self.to_raw())) }
+    }
+
+    /// Checks if a storage type is signed.
+    fn is_signed(&self) -> bool {
+        unsafe { mlirQuantizedTypeIsSigned(self.to_raw()) }
+    }
+
+    /// Returns a minimum value of a storage type.
+    fn storage_min(&self) -> i64 {
+        unsafe { mlirQuantizedTypeGetStorageTypeMin(self.to_raw()) }
+    }
+
+    /// Returns a maximum value of a storage type.
+    fn storage_max(&self) -> i64 {
+        unsafe { mlirQuantizedTypeGetStorageTypeMax(self.to_raw()) }
+    }
+
+    /// Returns an integral bit width of a storage type.
+    fn storage_integral_width(&self) -> usize {
+        (unsafe { mlirQuantizedTypeGetStorageTypeIntegralWidth(self.to_raw()) }) as usize
+    }
+
+    /// Checks if a type is compatible as an expressed type.
+    fn is_compatible_expressed_type(&self, candidate: Type<'c>) -> bool {
+        unsafe { mlirQuantizedTypeIsCompatibleExpressedType(self.to_raw(), candidate.to_raw()) }
+    }
+
+    /// Casts a type based on a storage type into one based on this type.
+    ///
+    /// For example, `tensor<4xi8>` is cast into `tensor<4x!quant.uniform<...>>`.
+    fn cast_from_storage_type(&self, candidate: Type<'c>) -> Option<Type<'c>> {
+        unsafe {
+            Type::from_option_raw(mlirQuantizedTypeCastFromStorageType(
+                self.to_raw(),
+                candidate.to_raw(),
+            ))
+        }
+    }
+
+    /// Casts a type based on an expressed type into one based on this type.
+    fn cast_from_expressed_type(&self, candidate: Type<'c>) -> Option<Type<'c>> {
+        unsafe {
+            Type::from_option_raw(mlirQuantizedTypeCastFromExpressedType(
+                self.to_raw(),
+                candidate.to_raw(),
+            ))
+        }
+    }
+
+    /// Casts a type based on an expressed type into one based on a storage
+    /// type.
+    fn cast_expressed_to_storage_type(&self, candidate: Type<'c>) -> Option<Type<'c>> {
+        unsafe {
+            Type::from_option_raw(mlirQuantizedTypeCastExpressedToStorageType(
+                self.to_raw(),
+                candidate.to_raw(),
+            ))
+        }
+    }
+
+    /// Casts this type into a storage type.
+    fn cast_to_storage_type(&self) -> Option<Type<'c>> {
+        unsafe { Type::from_option_raw(mlirQuantizedTypeCastToStorageType(self.to_raw())) }
+    }
+
+    /// Casts this type into an expressed type.
+    fn cast_to_expressed_type(&self) -> Option<Type<'c>> {
+        unsafe { Type::from_option_raw(mlirQuantizedTypeCastToExpressedType(self.to_raw())) }
+    }
+}
+
+pub(super) fn quantized_type_flags(signed: bool) -> u32 {
+    if signed {
+        unsafe { mlirQuantizedTypeGetSignedFlag() }
+    } else {
+        0
+    }
+}
+
+/// Checks parameters common to quantized types as MLIR's verifier does.
+pub(super) fn check_quantized_type(
+    signed: bool,
+    storage_type: Type,
+    expressed_type: Type,
+    storage_min: i64,
+    storage_max: i64,
+) -> Result<(), Error> {
+    let width = IntegerType::try_from(storage_type)?.width();
+
+    if width == 0 || width > 32 {
+        return Err(Error::InvalidQuantizationParameter {
+            name: "storage type",
+            value: storage_type.to_string(),
+        });
+    }
+
+    let (min, max) = if signed {
+        (-(1 << (width - 1)), (1 << (width - 1)) - 1)
+    } else {
+        (0, (1 << width) - 1)
+    };
+
+    if storage_min < min || storage_max > max {
+        return Err(Error::InvalidQuantizationParameter {
+            name: "storage range",
+            value: format!("[{storage_min}, {storage_max}] for {storage_type}"),
+        });
+    }
+
+    if !expressed_type.is_float() {
+        return Err(Error::TypeExpected("float", expressed_type.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Checks if a scale is positive and finite.
+pub(super) fn check_scale(scale: f64) -> Result<(), Error> {
+    if scale > 0.0 && scale.is_finite() {
+        Ok(())
+    } else {
+        Err(Error::InvalidQuantizationParameter {
+            name: "scale",
+            value: scale.to_string(),
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use super::{shaped_type_like::ShapedTypeLike, TypeLike};
+use super::{dimension::to_raw_dimensions, shaped_type_like::ShapedTypeLike, Dimension, TypeLike};
 use crate::{
     ir::{attribute::AttributeLike, Attribute, Location, Type},
     Error,
@@ -9,36 +9,44 @@ use mlir_sys::{
 };
 
 /// A ranked tensor type.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct RankedTensorType<'c> {
     r#type: Type<'c>,
 }
 
 impl<'c> RankedTensorType<'c> {
     /// Creates a ranked tensor type.
-    pub fn new(dimensions: &[u64], r#type: Type<'c>, encoding: Option<Attribute<'c>>) -> Self {
-        unsafe {
+    pub fn new(
+        dimensions: &[Dimension],
+        r#type: Type<'c>,
+        encoding: Option<Attribute<'c>>,
+    ) -> Result<Self, Error> {
+        let dimensions = to_raw_dimensions(dimensions)?;
+
+        Ok(unsafe {
             Self::from_raw(mlirRankedTensorTypeGet(
                 dimensions.len() as _,
-                dimensions.as_ptr() as *const _,
+                dimensions.as_ptr(),
                 r#type.to_raw(),
                 encoding.unwrap_or_else(|| Attribute::null()).to_raw(),
             ))
-        }
+        })
     }
 
     /// Creates a ranked type with diagnostics.
     pub fn checked(
-        dimensions: &[u64],
+        dimensions: &[Dimension],
         r#type: Type<'c>,
         encoding: Attribute<'c>,
         location: Location<'c>,
     ) -> Option<Self> {
+        let dimensions = to_raw_dimensions(dimensions).ok()?;
+
         unsafe {
             Self::from_option_raw(mlirRankedTensorTypeGetChecked(
                 location.to_raw(),
                 dimensions.len() as _,
-                dimensions.as_ptr() as *const _,
+                dimensions.as_ptr(),
                 r#type.to_raw(),
                 encoding.to_raw(),
             ))
@@ -73,7 +81,10 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            Type::from(RankedTensorType::new(&[42], Type::float64(&context), None)),
+            Type::from(
+                RankedTensorType::new(&[Dimension::Static(42)], Type::float64(&context), None)
+                    .unwrap()
+            ),
             Type::parse(&context, "tensor<42xf64>").unwrap()
         );
     }
@@ -83,8 +94,45 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            RankedTensorType::new(&[42, 42], Type::index(&context), None).encoding(),
+            RankedTensorType::new(
+                &[Dimension::Static(42), Dimension::Static(42)],
+                Type::index(&context),
+                None
+            )
+            .unwrap()
+            .encoding(),
+            None,
+        );
+    }
+
+    #[test]
+    fn dynamic_dimension() {
+        let context = Context::new();
+        let r#type = RankedTensorType::new(
+            &[Dimension::Dynamic, Dimension::Static(42)],
+            Type::float64(&context),
             None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Type::from(r#type),
+            Type::parse(&context, "tensor<?x42xf64>").unwrap()
+        );
+        assert_eq!(r#type.shape(), [Dimension::Dynamic, Dimension::Static(42)]);
+        assert_eq!(r#type.is_dynamic_dim(0), Ok(true));
+        assert_eq!(r#type.is_dynamic_dim(1), Ok(false));
+        assert_eq!(r#type.dynamic_dim_count(), 1);
+        assert!(!r#type.has_static_shape());
+    }
+
+    #[test]
+    fn too_large_dimension() {
+        let context = Context::new();
+
+        assert_eq!(
+            RankedTensorType::new(&[Dimension::Static(1 << 63)], Type::float64(&context), None),
+            Err(Error::InvalidDimension(1 << 63))
         );
     }
 }
@@ -1,9 +1,9 @@
 use crate::Error;
 
-use super::{Type, TypeLike};
+use super::{Dimension, Type, TypeLike};
 use mlir_sys::{
     mlirShapedTypeGetDimSize, mlirShapedTypeGetElementType, mlirShapedTypeGetRank,
-    mlirShapedTypeHasRank,
+    mlirShapedTypeHasRank, mlirShapedTypeHasStaticShape,
 };
 
 /// Trait for shaped types.
@@ -14,14 +14,22 @@ pub trait ShapedTypeLike<'c>: TypeLike<'c> {
     }
 
     /// Returns a rank.
+    ///
+    /// It is 0 for unranked types.
     fn rank(&self) -> usize {
-        (unsafe { mlirShapedTypeGetRank(self.to_raw()) }) as usize
+        if self.has_rank() {
+            (unsafe { mlirShapedTypeGetRank(self.to_raw()) }) as usize
+        } else {
+            0
+        }
     }
 
     /// Returns a dimension size.
-    fn dim_size(&self, index: usize) -> Result<usize, Error> {
+    fn dim_size(&self, index: usize) -> Result<Dimension, Error> {
         if index < self.rank() {
-            Ok((unsafe { mlirShapedTypeGetDimSize(self.to_raw(), index as isize) }) as usize)
+            Ok(Dimension::from_raw(unsafe {
+                mlirShapedTypeGetDimSize(self.to_raw(), index as isize)
+            }))
         } else {
             Err(Error::PositionOutOfBounds {
                 name: "dimension size",
@@ -31,10 +39,41 @@ pub trait ShapedTypeLike<'c>: TypeLike<'c> {
         }
     }
 
+    /// Returns a shape.
+    ///
+    /// It is empty for unranked types.
+    fn shape(&self) -> Vec<Dimension> {
+        (0..self.rank())
+            .map(|index| {
+                Dimension::from_raw(unsafe {
+                    mlirShapedTypeGetDimSize(self.to_raw(), index as isize)
+                })
+            })
+            .collect()
+    }
+
+    /// Checks if a dimension is dynamic.
+    fn is_dynamic_dim(&self, index: usize) -> Result<bool, Error> {
+        Ok(self.dim_size(index)?.is_dynamic())
+    }
+
+    /// Returns a number of dynamic dimensions.
+    fn dynamic_dim_count(&self) -> usize {
+        self.shape()
+            .into_iter()
+            .filter(Dimension::is_dynamic)
+            .count()
+    }
+
     /// Checks if a type has a rank.
     fn has_rank(&self) -> bool {
         unsafe { mlirShapedTypeHasRank(self.to_raw()) }
     }
+
+    /// Checks if a type has a static shape.
+    fn has_static_shape(&self) -> bool {
+        unsafe { mlirShapedTypeHasStaticShape(self.to_raw()) }
+    }
 }
 
 #[cfg(test)]
@@ -51,7 +90,9 @@ mod tests {
         let element_type = Type::index(&context);
 
         assert_eq!(
-            MemRefType::new(element_type, &[], None, None).element(),
+            MemRefType::new(element_type, &[], None, None)
+                .unwrap()
+                .element(),
             element_type
         );
     }
@@ -61,15 +102,26 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[], None, None).rank(),
+            MemRefType::new(Type::index(&context), &[], None, None)
+                .unwrap()
+                .rank(),
             0
         );
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[0], None, None).rank(),
+            MemRefType::new(Type::index(&context), &[Dimension::Static(0)], None, None)
+                .unwrap()
+                .rank(),
             1
         );
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[0, 0], None, None).rank(),
+            MemRefType::new(
+                Type::index(&context),
+                &[Dimension::Static(0), Dimension::Static(0)],
+                None,
+                None
+            )
+            .unwrap()
+            .rank(),
             2
         );
     }
@@ -79,7 +131,9 @@ mod tests {
         let context = Context::new();
 
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[], None, None).dim_size(0),
+            MemRefType::new(Type::index(&context), &[], None, None)
+                .unwrap()
+                .dim_size(0),
             Err(Error::PositionOutOfBounds {
                 name: "dimension size",
                 value: "memref<index>".into(),
@@ -87,22 +141,35 @@ mod tests {
             })
         );
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[42], None, None)
+            MemRefType::new(Type::index(&context), &[Dimension::Static(42)], None, None)
+                .unwrap()
                 .dim_size(0)
                 .unwrap(),
-            42
+            Dimension::Static(42)
         );
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[42, 0], None, None)
-                .dim_size(0)
-                .unwrap(),
-            42
+            MemRefType::new(
+                Type::index(&context),
+                &[Dimension::Static(42), Dimension::Static(0)],
+                None,
+                None
+            )
+            .unwrap()
+            .dim_size(0)
+            .unwrap(),
+            Dimension::Static(42)
         );
         assert_eq!(
-            MemRefType::new(Type::index(&context), &[0, 42], None, None)
-                .dim_size(1)
-                .unwrap(),
-            42
+            MemRefType::new(
+                Type::index(&context),
+                &[Dimension::Static(0), Dimension::Static(42)],
+                None,
+                None
+            )
+            .unwrap()
+            .dim_size(1)
+            .unwrap(),
+            Dimension::Static(42)
         );
     }
 
@@ -111,8 +178,21 @@ mod tests {
         let context = Context::new();
         let element_type = Type::index(&context);
 
-        assert!(MemRefType::new(element_type, &[], None, None).has_rank());
-        assert!(MemRefType::new(element_type, &[0], None, None).has_rank(),);
-        assert!(MemRefType::new(element_type, &[0, 0], None, None).has_rank(),);
+        assert!(MemRefType::new(element_type, &[], None, None)
+            .unwrap()
+            .has_rank());
+        assert!(
+            MemRefType::new(element_type, &[Dimension::Static(0)], None, None)
+                .unwrap()
+                .has_rank(),
+        );
+        assert!(MemRefType::new(
+            element_type,
+            &[Dimension::Static(0), Dimension::Static(0)],
+            None,
+            None
+        )
+        .unwrap()
+        .has_rank(),);
     }
 }
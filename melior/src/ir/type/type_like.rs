@@ -1,4 +1,4 @@
-use super::TypeId;
+use super::{float::float_kind, Type, TypeId};
 use crate::{context::ContextRef, dialect::Dialect};
 use mlir_sys::{mlirTypeDump, mlirTypeGetContext, mlirTypeGetDialect, mlirTypeGetTypeID, MlirType};
 
@@ -27,6 +27,11 @@ pub trait TypeLike<'c> {
         unsafe { mlirTypeDump(self.to_raw()) }
     }
 
+    /// Returns `true` if a type is a float.
+    fn is_float(&self) -> bool {
+        float_kind(unsafe { Type::from_raw(self.to_raw()) }).is_some()
+    }
+
     melior_macro::type_check_functions!(
         mlirTypeIsAAnyQuantizedType,
         mlirTypeIsABF16,
@@ -35,8 +40,11 @@ pub trait TypeLike<'c> {
         mlirTypeIsAF16,
         mlirTypeIsAF32,
         mlirTypeIsAF64,
+        mlirTypeIsAFloat8E4M3B11FNUZ,
         mlirTypeIsAFloat8E4M3FN,
+        mlirTypeIsAFloat8E4M3FNUZ,
         mlirTypeIsAFloat8E5M2,
+        mlirTypeIsAFloat8E5M2FNUZ,
         mlirTypeIsAFunction,
         mlirTypeIsAIndex,
         mlirTypeIsAInteger,
@@ -0,0 +1,276 @@
+use super::{
+    quantized_type_like::{check_quantized_type, check_scale, quantized_type_flags},
+    QuantizedTypeLike, TypeLike,
+};
+use crate::{ir::Type, Error};
+use mlir_sys::{
+    mlirUniformQuantizedTypeGet, mlirUniformQuantizedTypeGetScale,
+    mlirUniformQuantizedTypeGetZeroPoint, mlirUniformQuantizedTypeIsFixedPoint, MlirType,
+};
+
+/// A uniform quantized type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UniformQuantizedType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UniformQuantizedType<'c> {
+    /// Creates a uniform quantized type.
+    ///
+    /// A storage type must be an integer type of at most 32 bits whose range
+    /// contains a storage range, an expressed type must be a float type, and a
+    /// scale must be positive and finite.
+    pub fn new(
+        signed: bool,
+        storage_type: Type<'c>,
+        expressed_type: Type<'c>,
+        scale: f64,
+        zero_point: i64,
+        storage_min: i64,
+        storage_max: i64,
+    ) -> Result<Self, Error> {
+        check_quantized_type(
+            signed,
+            storage_type,
+            expressed_type,
+            storage_min,
+            storage_max,
+        )?;
+        check_scale(scale)?;
+
+        Ok(unsafe {
+            Self::from_raw(mlirUniformQuantizedTypeGet(
+                quantized_type_flags(signed),
+                storage_type.to_raw(),
+                expressed_type.to_raw(),
+                scale,
+                zero_point,
+                storage_min,
+                storage_max,
+            ))
+        })
+    }
+
+    /// Returns a scale.
+    pub fn scale(&self) -> f64 {
+        unsafe { mlirUniformQuantizedTypeGetScale(self.r#type.to_raw()) }
+    }
+
+    /// Returns a zero point.
+    pub fn zero_point(&self) -> i64 {
+        unsafe { mlirUniformQuantizedTypeGetZeroPoint(self.r#type.to_raw()) }
+    }
+
+    /// Checks if a type is fixed point.
+    pub fn is_fixed_point(&self) -> bool {
+        unsafe { mlirUniformQuantizedTypeIsFixedPoint(self.r#type.to_raw()) }
+    }
+}
+
+impl<'c> QuantizedTypeLike<'c> for UniformQuantizedType<'c> {}
+
+type_traits!(
+    UniformQuantizedType,
+    is_uniform_quantized_type,
+    "uniform quantized"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ir::r#type::{Dimension, IntegerType, RankedTensorType},
+        test::create_test_context,
+        Context,
+    };
+
+    fn create_type(context: &Context) -> UniformQuantizedType {
+        UniformQuantizedType::new(
+            true,
+            IntegerType::new(context, 8).into(),
+            Type::float32(context),
+            2.0,
+            10,
+            -128,
+            127,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::from(create_type(&context)),
+            Type::parse(&context, "!quant.uniform<i8:f32, 2.0:10>").unwrap()
+        );
+    }
+
+    #[test]
+    fn new_with_non_integer_storage_type() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedType::new(
+                true,
+                Type::float32(&context),
+                Type::float32(&context),
+                2.0,
+                10,
+                -128,
+                127,
+            ),
+            Err(Error::TypeExpected("integer", _))
+        ));
+    }
+
+    #[test]
+    fn new_with_too_wide_storage_type() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedType::new(
+                true,
+                IntegerType::new(&context, 64).into(),
+                Type::float32(&context),
+                2.0,
+                10,
+                -128,
+                127,
+            ),
+            Err(Error::InvalidQuantizationParameter {
+                name: "storage type",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn new_with_invalid_storage_range() {
+        let context = create_test_context();
+
+        for (signed, min, max) in [(true, -129, 127), (true, -128, 128), (false, -1, 255)] {
+            assert!(matches!(
+                UniformQuantizedType::new(
+                    signed,
+                    IntegerType::new(&context, 8).into(),
+                    Type::float32(&context),
+                    2.0,
+                    10,
+                    min,
+                    max,
+                ),
+                Err(Error::InvalidQuantizationParameter {
+                    name: "storage range",
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn new_with_non_float_expressed_type() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedType::new(
+                true,
+                IntegerType::new(&context, 8).into(),
+                IntegerType::new(&context, 32).into(),
+                2.0,
+                10,
+                -128,
+                127,
+            ),
+            Err(Error::TypeExpected("float", _))
+        ));
+    }
+
+    #[test]
+    fn new_with_invalid_scale() {
+        let context = create_test_context();
+
+        for scale in [0.0, -2.0, f64::INFINITY, f64::NAN] {
+            assert!(matches!(
+                UniformQuantizedType::new(
+                    true,
+                    IntegerType::new(&context, 8).into(),
+                    Type::float32(&context),
+                    scale,
+                    10,
+                    -128,
+                    127,
+                ),
+                Err(Error::InvalidQuantizationParameter { name: "scale", .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn parameters() {
+        let context = create_test_context();
+        let r#type = create_type(&context);
+
+        assert_eq!(r#type.scale(), 2.0);
+        assert_eq!(r#type.zero_point(), 10);
+        assert!(!r#type.is_fixed_point());
+        assert!(r#type.is_signed());
+    }
+
+    #[test]
+    fn try_from() {
+        let context = create_test_context();
+
+        assert!(UniformQuantizedType::try_from(
+            Type::parse(&context, "!quant.uniform<i8:f32, 2.0:10>").unwrap()
+        )
+        .is_ok());
+        assert!(matches!(
+            UniformQuantizedType::try_from(Type::float32(&context)),
+            Err(Error::TypeExpected("uniform quantized", _))
+        ));
+    }
+
+    #[test]
+    fn cast() {
+        let context = create_test_context();
+        let r#type = create_type(&context);
+        let storage_tensor_type = RankedTensorType::new(
+            &[Dimension::Static(4)],
+            IntegerType::new(&context, 8).into(),
+            None,
+        )
+        .unwrap()
+        .into();
+        let expressed_tensor_type =
+            RankedTensorType::new(&[Dimension::Static(4)], Type::float32(&context), None)
+                .unwrap()
+                .into();
+        let quantized_tensor_type =
+            Type::parse(&context, "tensor<4x!quant.uniform<i8:f32, 2.0:10>>");
+
+        assert_eq!(
+            r#type.cast_from_storage_type(storage_tensor_type),
+            quantized_tensor_type
+        );
+        assert_eq!(
+            r#type.cast_from_expressed_type(expressed_tensor_type),
+            quantized_tensor_type
+        );
+        assert_eq!(
+            r#type.cast_expressed_to_storage_type(expressed_tensor_type),
+            Some(storage_tensor_type)
+        );
+        assert_eq!(
+            r#type.cast_to_storage_type(),
+            Some(IntegerType::new(&context, 8).into())
+        );
+        assert_eq!(
+            r#type.cast_to_expressed_type(),
+            Some(Type::float32(&context))
+        );
+        assert!(r#type.is_compatible_expressed_type(Type::float32(&context)));
+        assert!(!r#type.is_compatible_expressed_type(Type::float64(&context)));
+    }
+}
@@ -0,0 +1,335 @@
+use super::{
+    quantized_type_like::{check_quantized_type, check_scale, quantized_type_flags},
+    QuantizedTypeLike, TypeLike,
+};
+use crate::{ir::Type, Error};
+use mlir_sys::{
+    mlirUniformQuantizedPerAxisTypeGet, mlirUniformQuantizedPerAxisTypeGetNumDims,
+    mlirUniformQuantizedPerAxisTypeGetQuantizedDimension, mlirUniformQuantizedPerAxisTypeGetScale,
+    mlirUniformQuantizedPerAxisTypeGetZeroPoint, mlirUniformQuantizedPerAxisTypeIsFixedPoint,
+    MlirType,
+};
+
+/// A uniform quantized type with parameters per axis.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UniformQuantizedPerAxisType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UniformQuantizedPerAxisType<'c> {
+    /// Creates a uniform quantized type with parameters per axis.
+    ///
+    /// A storage type must be an integer type of at most 32 bits whose range
+    /// contains a storage range, and an expressed type must be a float type.
+    /// Scales and zero points must be non-empty and of the same length, and
+    /// scales must be positive and finite. A quantized dimension must fit in a
+    /// 32-bit signed integer.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        signed: bool,
+        storage_type: Type<'c>,
+        expressed_type: Type<'c>,
+        scales: &[f64],
+        zero_points: &[i64],
+        quantized_dimension: usize,
+        storage_min: i64,
+        storage_max: i64,
+    ) -> Result<Self, Error> {
+        if scales.len() != zero_points.len() {
+            return Err(Error::ElementCountMismatch {
+                expected: scales.len(),
+                actual: zero_points.len(),
+            });
+        } else if scales.is_empty() {
+            return Err(Error::InvalidQuantizationParameter {
+                name: "scales",
+                value: "[]".into(),
+            });
+        }
+
+        check_quantized_type(
+            signed,
+            storage_type,
+            expressed_type,
+            storage_min,
+            storage_max,
+        )?;
+
+        for &scale in scales {
+            check_scale(scale)?;
+        }
+
+        let quantized_dimension =
+            i32::try_from(quantized_dimension).map_err(|_| Error::PositionOutOfBounds {
+                name: "quantized dimension",
+                value: expressed_type.to_string(),
+                index: quantized_dimension,
+            })?;
+        let mut scales = scales.to_vec();
+        let mut zero_points = zero_points.to_vec();
+
+        Ok(unsafe {
+            Self::from_raw(mlirUniformQuantizedPerAxisTypeGet(
+                quantized_type_flags(signed),
+                storage_type.to_raw(),
+                expressed_type.to_raw(),
+                scales.len() as isize,
+                scales.as_mut_ptr(),
+                zero_points.as_mut_ptr(),
+                quantized_dimension,
+                storage_min,
+                storage_max,
+            ))
+        })
+    }
+
+    /// Returns a number of scales and zero points.
+    pub fn len(&self) -> usize {
+        (unsafe { mlirUniformQuantizedPerAxisTypeGetNumDims(self.r#type.to_raw()) }) as usize
+    }
+
+    /// Checks if a type has no scales and zero points.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a scale.
+    pub fn scale(&self, index: usize) -> Result<f64, Error> {
+        self.check_index(index)?;
+
+        Ok(
+            unsafe {
+                mlirUniformQuantizedPerAxisTypeGetScale(self.r#type.to_raw(), index as isize)
+            },
+        )
+    }
+
+    /// Returns scales.
+    pub fn scales(&self) -> Vec<f64> {
+        (0..self.len())
+            .map(|index| unsafe {
+                mlirUniformQuantizedPerAxisTypeGetScale(self.r#type.to_raw(), index as isize)
+            })
+            .collect()
+    }
+
+    /// Returns a zero point.
+    pub fn zero_point(&self, index: usize) -> Result<i64, Error> {
+        self.check_index(index)?;
+
+        Ok(unsafe {
+            mlirUniformQuantizedPerAxisTypeGetZeroPoint(self.r#type.to_raw(), index as isize)
+        })
+    }
+
+    /// Returns zero points.
+    pub fn zero_points(&self) -> Vec<i64> {
+        (0..self.len())
+            .map(|index| unsafe {
+                mlirUniformQuantizedPerAxisTypeGetZeroPoint(self.r#type.to_raw(), index as isize)
+            })
+            .collect()
+    }
+
+    /// Returns a quantized dimension.
+    pub fn quantized_dimension(&self) -> usize {
+        (unsafe { mlirUniformQuantizedPerAxisTypeGetQuantizedDimension(self.r#type.to_raw()) })
+            as usize
+    }
+
+    /// Checks if a type is fixed point.
+    pub fn is_fixed_point(&self) -> bool {
+        unsafe { mlirUniformQuantizedPerAxisTypeIsFixedPoint(self.r#type.to_raw()) }
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), Error> {
+        if index < self.len() {
+            Ok(())
+        } else {
+            Err(Error::PositionOutOfBounds {
+                name: "quantization parameter",
+                value: self.to_string(),
+                index,
+            })
+        }
+    }
+}
+
+impl<'c> QuantizedTypeLike<'c> for UniformQuantizedPerAxisType<'c> {}
+
+type_traits!(
+    UniformQuantizedPerAxisType,
+    is_uniform_quantized_per_axis_type,
+    "uniform quantized per axis"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::r#type::IntegerType, test::create_test_context, Context};
+
+    fn create_type(context: &Context) -> UniformQuantizedPerAxisType {
+        UniformQuantizedPerAxisType::new(
+            true,
+            IntegerType::new(context, 8).into(),
+            Type::float32(context),
+            &[2.0, 3.0],
+            &[10, 20],
+            1,
+            -128,
+            127,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let context = create_test_context();
+
+        assert_eq!(
+            Type::from(create_type(&context)),
+            Type::parse(&context, "!quant.uniform<i8:f32:1, {2.0:10, 3.0:20}>").unwrap()
+        );
+    }
+
+    #[test]
+    fn new_with_invalid_zero_points() {
+        let context = create_test_context();
+
+        assert_eq!(
+            UniformQuantizedPerAxisType::new(
+                true,
+                IntegerType::new(&context, 8).into(),
+                Type::float32(&context),
+                &[2.0, 3.0],
+                &[10],
+                1,
+                -128,
+                127,
+            ),
+            Err(Error::ElementCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn new_with_empty_parameters() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedPerAxisType::new(
+                true,
+                IntegerType::new(&context, 8).into(),
+                Type::float32(&context),
+                &[],
+                &[],
+                1,
+                -128,
+                127,
+            ),
+            Err(Error::InvalidQuantizationParameter { name: "scales", .. })
+        ));
+    }
+
+    #[test]
+    fn new_with_invalid_scale() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedPerAxisType::new(
+                true,
+                IntegerType::new(&context, 8).into(),
+                Type::float32(&context),
+                &[2.0, 0.0],
+                &[10, 20],
+                1,
+                -128,
+                127,
+            ),
+            Err(Error::InvalidQuantizationParameter { name: "scale", .. })
+        ));
+    }
+
+    #[test]
+    fn new_with_non_integer_storage_type() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedPerAxisType::new(
+                true,
+                Type::float32(&context),
+                Type::float32(&context),
+                &[2.0, 3.0],
+                &[10, 20],
+                1,
+                -128,
+                127,
+            ),
+            Err(Error::TypeExpected("integer", _))
+        ));
+    }
+
+    #[test]
+    fn new_with_invalid_storage_range() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedPerAxisType::new(
+                true,
+                IntegerType::new(&context, 8).into(),
+                Type::float32(&context),
+                &[2.0, 3.0],
+                &[10, 20],
+                1,
+                -128,
+                255,
+            ),
+            Err(Error::InvalidQuantizationParameter {
+                name: "storage range",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn new_with_invalid_quantized_dimension() {
+        let context = create_test_context();
+
+        assert!(matches!(
+            UniformQuantizedPerAxisType::new(
+                true,
+                IntegerType::new(&context, 8).into(),
+                Type::float32(&context),
+                &[2.0, 3.0],
+                &[10, 20],
+                i32::MAX as usize + 1,
+                -128,
+                127,
+            ),
+            Err(Error::PositionOutOfBounds {
+                name: "quantized dimension",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parameters() {
+        let context = create_test_context();
+        let r#type = create_type(&context);
+
+        assert_eq!(r#type.len(), 2);
+        assert_eq!(r#type.scale(1), Ok(3.0));
+        assert_eq!(r#type.scales(), [2.0, 3.0]);
+        assert_eq!(r#type.zero_point(1), Ok(20));
+        assert_eq!(r#type.zero_points(), [10, 20]);
+        assert_eq!(r#type.quantized_dimension(), 1);
+        assert!(!r#type.is_fixed_point());
+        assert!(matches!(
+            r#type.scale(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+}
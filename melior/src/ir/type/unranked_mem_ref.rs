@@ -0,0 +1,102 @@
+use super::{shaped_type_like::ShapedTypeLike, TypeLike};
+use crate::{
+    ir::{attribute::AttributeLike, Attribute, Location, Type},
+    Error,
+};
+use mlir_sys::{
+    mlirUnrankedMemRefTypeGet, mlirUnrankedMemRefTypeGetChecked, mlirUnrankedMemrefGetMemorySpace,
+    MlirType,
+};
+
+/// An unranked mem-ref type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnrankedMemRefType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UnrankedMemRefType<'c> {
+    /// Creates an unranked mem-ref type.
+    pub fn new(r#type: Type<'c>, memory_space: Option<Attribute<'c>>) -> Self {
+        unsafe {
+            Self::from_raw(mlirUnrankedMemRefTypeGet(
+                r#type.to_raw(),
+                memory_space.unwrap_or_else(|| Attribute::null()).to_raw(),
+            ))
+        }
+    }
+
+    /// Creates an unranked mem-ref type with diagnostics.
+    pub fn checked(
+        location: Location<'c>,
+        r#type: Type<'c>,
+        memory_space: Option<Attribute<'c>>,
+    ) -> Option<Self> {
+        unsafe {
+            Self::from_option_raw(mlirUnrankedMemRefTypeGetChecked(
+                location.to_raw(),
+                r#type.to_raw(),
+                memory_space.unwrap_or_else(|| Attribute::null()).to_raw(),
+            ))
+        }
+    }
+
+    /// Returns a memory space.
+    pub fn memory_space(&self) -> Option<Attribute<'c>> {
+        unsafe {
+            Attribute::from_option_raw(mlirUnrankedMemrefGetMemorySpace(self.r#type.to_raw()))
+        }
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+}
+
+impl<'c> ShapedTypeLike<'c> for UnrankedMemRefType<'c> {}
+
+type_traits!(UnrankedMemRefType, is_unranked_mem_ref, "unranked mem-ref");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ir::attribute::IntegerAttribute, Context};
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(UnrankedMemRefType::new(Type::float64(&context), None)),
+            Type::parse(&context, "memref<*xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            UnrankedMemRefType::checked(Location::unknown(&context), Type::none(&context), None),
+            None
+        );
+    }
+
+    #[test]
+    fn memory_space() {
+        let context = Context::new();
+        let memory_space = IntegerAttribute::new(Type::parse(&context, "i64").unwrap(), 1).into();
+
+        assert_eq!(
+            UnrankedMemRefType::new(Type::index(&context), None).memory_space(),
+            None
+        );
+        assert_eq!(
+            UnrankedMemRefType::new(Type::index(&context), Some(memory_space)).memory_space(),
+            Some(memory_space)
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use super::{shaped_type_like::ShapedTypeLike, TypeLike};
+use crate::{
+    ir::{Location, Type},
+    Error,
+};
+use mlir_sys::{mlirUnrankedTensorTypeGet, mlirUnrankedTensorTypeGetChecked, MlirType};
+
+/// An unranked tensor type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnrankedTensorType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> UnrankedTensorType<'c> {
+    /// Creates an unranked tensor type.
+    pub fn new(r#type: Type<'c>) -> Self {
+        unsafe { Self::from_raw(mlirUnrankedTensorTypeGet(r#type.to_raw())) }
+    }
+
+    /// Creates an unranked tensor type with diagnostics.
+    pub fn checked(location: Location<'c>, r#type: Type<'c>) -> Option<Self> {
+        unsafe {
+            Self::from_option_raw(mlirUnrankedTensorTypeGetChecked(
+                location.to_raw(),
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+}
+
+impl<'c> ShapedTypeLike<'c> for UnrankedTensorType<'c> {}
+
+type_traits!(UnrankedTensorType, is_unranked_tensor, "unranked tensor");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(UnrankedTensorType::new(Type::float64(&context))),
+            Type::parse(&context, "tensor<*xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            UnrankedTensorType::checked(Location::unknown(&context), Type::none(&context)),
+            None
+        );
+    }
+
+    #[test]
+    fn shaped() {
+        let context = Context::new();
+        let r#type = UnrankedTensorType::new(Type::index(&context));
+
+        assert_eq!(r#type.element(), Type::index(&context));
+        assert!(!r#type.has_rank());
+        assert!(r#type.dim_size(0).is_err());
+    }
+}
@@ -0,0 +1,251 @@
+use super::{dimension::to_raw_dimensions, shaped_type_like::ShapedTypeLike, Dimension, TypeLike};
+use crate::{
+    ir::{Location, Type},
+    Error, StringRef,
+};
+use mlir_sys::{
+    mlirTypeGetContext, mlirTypeParseGet, mlirVectorTypeGet, mlirVectorTypeGetChecked, MlirType,
+};
+
+/// A vector type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VectorType<'c> {
+    r#type: Type<'c>,
+}
+
+impl<'c> VectorType<'c> {
+    /// Creates a vector type.
+    ///
+    /// Vector dimensions must be static. Use [`VectorType::checked`] to
+    /// validate them.
+    pub fn new(dimensions: &[Dimension], r#type: Type<'c>) -> Result<Self, Error> {
+        let dimensions = to_raw_dimensions(dimensions)?;
+
+        Ok(unsafe {
+            Self::from_raw(mlirVectorTypeGet(
+                dimensions.len() as isize,
+                dimensions.as_ptr(),
+                r#type.to_raw(),
+            ))
+        })
+    }
+
+    /// Creates a vector type with diagnostics.
+    pub fn checked(
+        location: Location<'c>,
+        dimensions: &[Dimension],
+        r#type: Type<'c>,
+    ) -> Option<Self> {
+        let dimensions = to_raw_dimensions(dimensions).ok()?;
+
+        unsafe {
+            Self::from_option_raw(mlirVectorTypeGetChecked(
+                location.to_raw(),
+                dimensions.len() as isize,
+                dimensions.as_ptr(),
+                r#type.to_raw(),
+            ))
+        }
+    }
+
+    /// Creates a vector type with scalable dimensions.
+    ///
+    /// Each flag in `scalable` tells if the corresponding dimension is
+    /// scalable.
+    pub fn scalable(
+        dimensions: &[Dimension],
+        scalable: &[bool],
+        r#type: Type<'c>,
+    ) -> Result<Self, Error> {
+        if dimensions.len() != scalable.len() {
+            return Err(Error::ElementCountMismatch {
+                expected: dimensions.len(),
+                actual: scalable.len(),
+            });
+        }
+
+        // TODO Use `mlirVectorTypeGetScalable` when we upgrade to MLIR 18.
+        let source = format!(
+            "vector<{}>",
+            dimensions
+                .iter()
+                .zip(scalable)
+                .map(|(dimension, &scalable)| if scalable {
+                    format!("[{dimension}]")
+                } else {
+                    dimension.to_string()
+                })
+                .chain([r#type.to_string()])
+                .collect::<Vec<_>>()
+                .join("x")
+        );
+
+        unsafe {
+            Self::from_option_raw(mlirTypeParseGet(
+                mlirTypeGetContext(r#type.to_raw()),
+                StringRef::new(&source).to_raw(),
+            ))
+        }
+        .ok_or(Error::TypeExpected("vector", source))
+    }
+
+    /// Returns `true` if any dimension is scalable.
+    pub fn is_scalable(&self) -> bool {
+        self.scalable_dimensions()
+            .into_iter()
+            .any(|scalable| scalable)
+    }
+
+    /// Returns `true` if a dimension is scalable.
+    pub fn is_dimension_scalable(&self, index: usize) -> Result<bool, Error> {
+        self.scalable_dimensions()
+            .get(index)
+            .copied()
+            .ok_or_else(|| Error::PositionOutOfBounds {
+                name: "vector dimension",
+                value: self.to_string(),
+                index,
+            })
+    }
+
+    /// Returns flags of scalable dimensions.
+    pub fn scalable_dimensions(&self) -> Vec<bool> {
+        // TODO Use `mlirVectorTypeIsDimScalable` when we upgrade to MLIR 18.
+        // Until then, scalable dimensions are read from an assembly format of
+        // `vector<[4]x8xf32>`.
+        self.to_string()
+            .strip_prefix("vector<")
+            .unwrap_or_default()
+            .split('x')
+            .take(self.rank())
+            .map(|dimension| dimension.starts_with('['))
+            .collect()
+    }
+
+    unsafe fn from_option_raw(raw: MlirType) -> Option<Self> {
+        if raw.ptr.is_null() {
+            None
+        } else {
+            Some(Self::from_raw(raw))
+        }
+    }
+}
+
+impl<'c> ShapedTypeLike<'c> for VectorType<'c> {}
+
+type_traits!(VectorType, is_vector, "vector");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Context;
+
+    #[test]
+    fn new() {
+        let context = Context::new();
+
+        assert_eq!(
+            Type::from(VectorType::new(&[Dimension::Static(42)], Type::float64(&context)).unwrap()),
+            Type::parse(&context, "vector<42xf64>").unwrap()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let context = Context::new();
+
+        assert_eq!(
+            VectorType::checked(
+                Location::unknown(&context),
+                &[Dimension::Static(0)],
+                Type::index(&context)
+            ),
+            None
+        );
+        assert_eq!(
+            VectorType::checked(
+                Location::unknown(&context),
+                &[Dimension::Dynamic],
+                Type::index(&context)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn scalable() {
+        let context = Context::new();
+        let r#type = VectorType::scalable(
+            &[Dimension::Static(4), Dimension::Static(8)],
+            &[true, false],
+            Type::float32(&context),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Type::from(r#type),
+            Type::parse(&context, "vector<[4]x8xf32>").unwrap()
+        );
+        assert_eq!(r#type.rank(), 2);
+        assert_eq!(r#type.dim_size(0), Ok(Dimension::Static(4)));
+        assert_eq!(r#type.dim_size(1), Ok(Dimension::Static(8)));
+        assert!(r#type.is_scalable());
+        assert_eq!(r#type.is_dimension_scalable(0), Ok(true));
+        assert_eq!(r#type.is_dimension_scalable(1), Ok(false));
+        assert!(matches!(
+            r#type.is_dimension_scalable(2),
+            Err(Error::PositionOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn scalable_with_invalid_flags() {
+        let context = Context::new();
+
+        assert_eq!(
+            VectorType::scalable(&[Dimension::Static(4)], &[], Type::float32(&context)),
+            Err(Error::ElementCountMismatch {
+                expected: 1,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    fn scalable_with_dynamic_dimension() {
+        let context = Context::new();
+
+        assert!(matches!(
+            VectorType::scalable(&[Dimension::Dynamic], &[true], Type::float32(&context)),
+            Err(Error::TypeExpected("vector", _))
+        ));
+    }
+
+    #[test]
+    fn not_scalable() {
+        let context = Context::new();
+        let r#type = VectorType::new(
+            &[Dimension::Static(4), Dimension::Static(8)],
+            Type::index(&context),
+        )
+        .unwrap();
+
+        assert!(!r#type.is_scalable());
+        assert_eq!(r#type.scalable_dimensions(), [false, false]);
+    }
+
+    #[test]
+    fn try_from() {
+        let context = Context::new();
+
+        assert!(
+            VectorType::try_from(Type::parse(&context, "vector<[4]xi8>").unwrap())
+                .unwrap()
+                .is_scalable()
+        );
+        assert!(matches!(
+            VectorType::try_from(Type::index(&context)),
+            Err(Error::TypeExpected("vector", _))
+        ));
+    }
+}
@@ -24,7 +24,7 @@ pub use self::{
     string_ref::StringRef,
 };
 
-pub use melior_macro::dialect;
+pub use melior_macro::{dialect, ExternalPass};
 
 #[cfg(test)]
 mod tests {
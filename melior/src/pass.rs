@@ -1,25 +1,44 @@
 //! Passes and pass managers.
 
 pub mod r#async;
+mod bisection;
 pub mod conversion;
 pub mod external;
 pub mod gpu;
+mod instrumentation;
+mod ir_printing;
 pub mod linalg;
 mod manager;
+mod observer;
 mod operation_manager;
+pub mod options;
+mod pipeline;
+mod pipeline_builder;
 pub mod sparse_tensor;
+mod statistic;
+mod timing;
 pub mod transform;
 
+pub(crate) use self::pipeline::{parse_pipeline, PipelineElement};
 pub use self::{
+    bisection::{BisectedPass, BisectionCause},
     external::{create_external, ExternalPass, RunExternalPass},
+    instrumentation::PassInstrumentation,
+    ir_printing::IrPrintingConfiguration,
     manager::PassManager,
     operation_manager::OperationPassManager,
+    pipeline_builder::PipelineBuilder,
+    statistic::{PassStatistic, PassStatistics, StatisticsReport},
+    timing::{PassTiming, TimingReport},
 };
 use mlir_sys::MlirPass;
 
 /// A pass.
 pub struct Pass {
     raw: MlirPass,
+    argument: Option<String>,
+    options: Option<String>,
+    statistics: Vec<PassStatistic>,
 }
 
 impl Pass {
@@ -29,9 +48,7 @@ impl Pass {
     ///
     /// A raw function must be valid.
     pub unsafe fn from_raw_fn(create_raw: unsafe extern "C" fn() -> MlirPass) -> Self {
-        Self {
-            raw: unsafe { create_raw() },
-        }
+        Self::from_raw(create_raw())
     }
 
     /// Creates a pass from a raw object.
@@ -40,7 +57,22 @@ impl Pass {
     ///
     /// A raw object must be valid.
     pub const unsafe fn from_raw(raw: MlirPass) -> Self {
-        Self { raw }
+        Self {
+            raw,
+            argument: None,
+            options: None,
+            statistics: Vec::new(),
+        }
+    }
+
+    /// Sets options of an external pass in a textual form.
+    ///
+    /// Pass pipelines print the options in braces after an argument of the
+    /// pass, such as `my-pass{max-depth=2}`. Native passes print their own
+    /// options and ignore them.
+    pub fn with_options(mut self, options: &str) -> Self {
+        self.options = (!options.is_empty()).then(|| options.into());
+        self
     }
 
     /// Converts a pass into a raw object.
@@ -48,6 +80,10 @@ impl Pass {
         self.raw
     }
 
+    pub(crate) fn options(&self) -> Option<&str> {
+        self.options.as_deref()
+    }
+
     #[doc(hidden)]
     pub unsafe fn __private_from_raw_fn(create_raw: unsafe extern "C" fn() -> MlirPass) -> Self {
         Self::from_raw_fn(create_raw)
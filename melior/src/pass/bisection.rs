@@ -0,0 +1,64 @@
+/// A cause of a bisected pass.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BisectionCause {
+    /// A pass failed.
+    PassFailure,
+    /// An operation failed verification after a pass.
+    VerificationFailure,
+    /// A predicate flipped after a pass.
+    PredicateFlip,
+}
+
+/// The first pass in a pipeline found by bisection.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BisectedPass {
+    index: usize,
+    name: String,
+    nesting: Vec<String>,
+    cause: BisectionCause,
+    input: String,
+}
+
+impl BisectedPass {
+    pub(crate) fn new(
+        index: usize,
+        name: String,
+        nesting: Vec<String>,
+        cause: BisectionCause,
+        input: String,
+    ) -> Self {
+        Self {
+            index,
+            name,
+            nesting,
+            cause,
+            input,
+        }
+    }
+
+    /// Returns an index of a pass in execution order.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a pass name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns names of operations a pass is nested under from the outermost
+    /// one.
+    pub fn nesting(&self) -> &[String] {
+        &self.nesting
+    }
+
+    /// Returns a cause.
+    pub fn cause(&self) -> BisectionCause {
+        self.cause
+    }
+
+    /// Returns IR in a generic form right before a pass.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
@@ -1,6 +1,6 @@
 //! External passes
 
-use super::Pass;
+use super::{Pass, PassStatistic};
 use crate::{
     dialect::DialectHandle,
     ir::{r#type::TypeId, OperationRef},
@@ -12,6 +12,11 @@ use mlir_sys::{
 };
 use std::{marker::PhantomData, mem::transmute, ptr::drop_in_place};
 
+/// A handle of an external pass being run.
+///
+/// All analyses are invalidated after an external pass runs. Analyses cannot be
+/// marked as preserved because the C API of MLIR 17 does not expose analysis
+/// preservation.
 #[derive(Clone, Copy, Debug)]
 pub struct ExternalPass<'a> {
     raw: MlirExternalPass,
@@ -92,6 +97,14 @@ unsafe extern "C" fn callback_clone<'a, T: RunExternalPass<'a>>(pass: *mut T) ->
 /// This trait is implemented for any type that implements `FnMut`,
 /// but can be implemented for any struct that implements `Clone`.
 ///
+/// Statistics can be kept in [`PassStatistic`] fields, which are shared by
+/// clones of a pass, and are reported by
+/// [`PassManager::statistics_report`](super::PassManager::statistics_report)
+/// if they are returned by [`statistics`](Self::statistics). Textual options given to a pass
+/// registered with
+/// [`register_external_pass`](crate::utility::register_external_pass) can be
+/// parsed with [`parse_options`](super::options::parse_options).
+///
 /// # Examples
 ///
 /// The following example pass dumps operations.
@@ -125,6 +138,11 @@ pub trait RunExternalPass<'c>: Sized + Clone {
     fn destruct(&mut self) {}
     fn initialize(&mut self, context: ContextRef<'c>);
     fn run(&mut self, operation: OperationRef<'c, '_>, pass: ExternalPass<'_>);
+
+    /// Returns statistics.
+    fn statistics(&self) -> Vec<PassStatistic> {
+        vec![]
+    }
 }
 
 impl<'c, F: FnMut(OperationRef<'c, '_>, ExternalPass<'_>) + Clone> RunExternalPass<'c> for F {
@@ -137,6 +155,10 @@ impl<'c, F: FnMut(OperationRef<'c, '_>, ExternalPass<'_>) + Clone> RunExternalPa
 
 /// Creates a `Pass` object from an external pass
 ///
+/// Pass pipelines print external passes by their arguments. Options of a pass
+/// can be set with [`Pass::with_options`] so that printed pipelines reproduce
+/// them.
+///
 /// # Examples
 ///
 /// ```
@@ -175,7 +197,9 @@ pub fn create_external<'c, T: RunExternalPass<'c>>(
     op_name: &str,
     dependent_dialects: &[DialectHandle],
 ) -> Pass {
-    unsafe {
+    let statistics = pass.statistics();
+
+    let mut pass = unsafe {
         Pass::from_raw(mlirCreateExternalPass(
             pass_id.to_raw(),
             StringRef::new(name).to_raw(),
@@ -193,7 +217,12 @@ pub fn create_external<'c, T: RunExternalPass<'c>>(
             },
             Box::into_raw(Box::new(pass)) as _,
         ))
-    }
+    };
+
+    pass.argument = Some(argument.into());
+    pass.statistics = statistics;
+
+    pass
 }
 
 #[cfg(test)]
@@ -206,7 +235,7 @@ mod tests {
             r#type::FunctionType,
             Block, Identifier, Location, Module, Region,
         },
-        pass::PassManager,
+        pass::{PassManager, PassStatistic},
         test::create_test_context,
         Context,
     };
@@ -339,4 +368,57 @@ mod tests {
         ));
         assert!(pass_manager.run(&mut module).is_err());
     }
+
+    #[test]
+    fn external_pass_statistic() {
+        static TEST_PASS: PassId = PassId;
+
+        #[derive(Clone, Debug)]
+        struct TestPass {
+            functions: PassStatistic,
+        }
+
+        impl<'c> RunExternalPass<'c> for TestPass {
+            fn initialize(&mut self, _context: ContextRef<'c>) {}
+
+            fn run(&mut self, _operation: OperationRef<'c, '_>, _pass: ExternalPass<'_>) {
+                self.functions.increment();
+            }
+
+            fn statistics(&self) -> Vec<PassStatistic> {
+                vec![self.functions.clone()]
+            }
+        }
+
+        let context = create_test_context();
+        let mut module = create_module(&context);
+        let pass_manager = PassManager::new(&context);
+        let functions = PassStatistic::new("functions", "Number of functions");
+
+        pass_manager
+            .nested_under("func.func")
+            .add_pass(create_external(
+                TestPass {
+                    functions: functions.clone(),
+                },
+                TypeId::create(&TEST_PASS),
+                "test pass",
+                "test-argument",
+                "a test pass",
+                "func.func",
+                &[],
+            ));
+        pass_manager.run(&mut module).unwrap();
+        pass_manager.run(&mut module).unwrap();
+
+        assert_eq!(functions.value(), 2);
+
+        let report = pass_manager.statistics_report();
+
+        assert_eq!(report.passes()[0].name(), "test-argument");
+        assert_eq!(report.passes()[0].statistics()[0].value(), 2);
+        assert!(report
+            .to_string()
+            .contains("    (S) 2 functions - Number of functions\n"));
+    }
 }
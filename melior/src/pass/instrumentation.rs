@@ -0,0 +1,19 @@
+use crate::ir::OperationRef;
+
+/// A pass instrumentation.
+///
+/// Hooks are called for each operation a pass runs on with an argument of the
+/// pass while a pass manager runs.
+///
+/// Unlike MLIR's pass instrumentations, it does not have hooks for analyses
+/// because the C API does not expose analyses or notify their computation.
+pub trait PassInstrumentation {
+    /// Runs before a pass.
+    fn run_before_pass(&mut self, _pass: &str, _operation: OperationRef) {}
+
+    /// Runs after a pass succeeds.
+    fn run_after_pass(&mut self, _pass: &str, _operation: OperationRef) {}
+
+    /// Runs after a pass fails.
+    fn run_after_pass_failed(&mut self, _pass: &str, _operation: OperationRef) {}
+}
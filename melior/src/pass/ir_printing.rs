@@ -0,0 +1,179 @@
+use crate::{
+    ir::{operation::OperationPrintingFlags, OperationRef},
+    utility::print_string_callback,
+    Error,
+};
+use mlir_sys::mlirOperationPrintWithFlags;
+use std::io::Write;
+
+type Sink = Box<dyn FnMut(&str) -> Result<(), Error>>;
+
+/// An IR printing configuration of a pass manager.
+///
+/// By default, IR is printed before and after every pass in module scope.
+pub struct IrPrintingConfiguration {
+    before: Box<dyn Fn(&str) -> bool>,
+    after: Box<dyn Fn(&str) -> bool>,
+    only_on_change: bool,
+    only_on_failure: bool,
+    module_scope: bool,
+    flags: OperationPrintingFlags,
+    sink: Sink,
+}
+
+impl IrPrintingConfiguration {
+    /// Creates an IR printing configuration writing IR into a writer.
+    pub fn new(mut writer: impl Write + 'static) -> Self {
+        Self::with_sink(Box::new(move |string| {
+            writer
+                .write_all(string.as_bytes())
+                .map_err(|error| Error::PrintIr(error.to_string()))
+        }))
+    }
+
+    /// Creates an IR printing configuration passing IR to a callback.
+    pub fn with_callback(mut callback: impl FnMut(&str) + 'static) -> Self {
+        Self::with_sink(Box::new(move |string| {
+            callback(string);
+
+            Ok(())
+        }))
+    }
+
+    fn with_sink(sink: Sink) -> Self {
+        Self {
+            before: Box::new(|_| true),
+            after: Box::new(|_| true),
+            only_on_change: false,
+            only_on_failure: false,
+            module_scope: true,
+            flags: OperationPrintingFlags::new(),
+            sink,
+        }
+    }
+
+    /// Prints IR before passes of given arguments.
+    pub fn print_before(self, arguments: &[&str]) -> Self {
+        let arguments = arguments.iter().map(|&name| name.to_owned()).collect();
+
+        self.print_before_if(contains(arguments))
+    }
+
+    /// Prints IR before passes whose arguments satisfy a predicate.
+    pub fn print_before_if(mut self, predicate: impl Fn(&str) -> bool + 'static) -> Self {
+        self.before = Box::new(predicate);
+        self
+    }
+
+    /// Prints IR after passes of given arguments.
+    pub fn print_after(self, arguments: &[&str]) -> Self {
+        let arguments = arguments.iter().map(|&name| name.to_owned()).collect();
+
+        self.print_after_if(contains(arguments))
+    }
+
+    /// Prints IR after passes whose arguments satisfy a predicate.
+    pub fn print_after_if(mut self, predicate: impl Fn(&str) -> bool + 'static) -> Self {
+        self.after = Box::new(predicate);
+        self
+    }
+
+    /// Prints IR after passes only if they change it.
+    pub fn print_only_on_change(mut self, enabled: bool) -> Self {
+        self.only_on_change = enabled;
+        self
+    }
+
+    /// Prints IR after passes only if they fail.
+    pub fn print_only_on_failure(mut self, enabled: bool) -> Self {
+        self.only_on_failure = enabled;
+        self
+    }
+
+    /// Prints a whole operation a pass manager runs on rather than operations
+    /// a pass runs on.
+    pub fn print_module_scope(mut self, enabled: bool) -> Self {
+        self.module_scope = enabled;
+        self
+    }
+
+    /// Sets operation printing flags.
+    pub fn flags(mut self, flags: OperationPrintingFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub(crate) fn is_module_scope(&self) -> bool {
+        self.module_scope
+    }
+
+    /// Prints IR before a pass and returns it if it is compared with IR after
+    /// the pass.
+    pub(crate) fn print_before_pass(
+        &mut self,
+        pass: &str,
+        operation: OperationRef,
+    ) -> Result<Option<String>, Error> {
+        let print = (self.before)(pass);
+
+        if !print && !(self.only_on_change && (self.after)(pass)) {
+            return Ok(None);
+        }
+
+        let string = self.print(operation)?;
+
+        if print {
+            (self.sink)(&format!("// -----// IR Dump Before {pass} //----- //\n"))?;
+            (self.sink)(&string)?;
+        }
+
+        Ok(self.only_on_change.then_some(string))
+    }
+
+    pub(crate) fn print_after_pass(
+        &mut self,
+        pass: &str,
+        operation: OperationRef,
+        previous: Option<&str>,
+        failed: bool,
+    ) -> Result<(), Error> {
+        if !(self.after)(pass) || self.only_on_failure && !failed {
+            return Ok(());
+        }
+
+        let string = self.print(operation)?;
+
+        if self.only_on_change && !failed && previous == Some(string.as_str()) {
+            return Ok(());
+        }
+
+        let suffix = if failed { " Failed" } else { "" };
+
+        (self.sink)(&format!(
+            "// -----// IR Dump After {pass}{suffix} //----- //\n"
+        ))?;
+        (self.sink)(&string)
+    }
+
+    fn print(&self, operation: OperationRef) -> Result<String, Error> {
+        let mut data = (String::new(), Ok::<_, Error>(()));
+
+        unsafe {
+            mlirOperationPrintWithFlags(
+                operation.to_raw(),
+                self.flags.to_raw(),
+                Some(print_string_callback),
+                &mut data as *mut _ as *mut _,
+            );
+        }
+
+        data.1?;
+        data.0.push('\n');
+
+        Ok(data.0)
+    }
+}
+
+fn contains(arguments: Vec<String>) -> impl Fn(&str) -> bool {
+    move |pass| arguments.iter().any(|argument| argument == pass)
+}
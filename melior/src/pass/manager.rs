@@ -1,61 +1,256 @@
-use super::OperationPassManager;
+use super::{
+    observer::{Observer, Run},
+    pipeline::{parse_pipeline, PipelineElement},
+    BisectedPass, BisectionCause, IrPrintingConfiguration, OperationPassManager,
+    PassInstrumentation, StatisticsReport, TimingReport,
+};
 use crate::{
-    context::Context, ir::Module, logical_result::LogicalResult, pass::Pass, string_ref::StringRef,
+    context::Context,
+    ir::{operation::OperationPrintingFlags, Module, Operation, OperationRef},
+    logical_result::LogicalResult,
+    pass::Pass,
+    string_ref::StringRef,
+    utility::add_pipeline_elements,
     Error,
 };
 use mlir_sys::{
-    mlirPassManagerAddOwnedPass, mlirPassManagerCreate, mlirPassManagerDestroy,
+    mlirPassManagerCreate, mlirPassManagerCreateOnOperation, mlirPassManagerDestroy,
     mlirPassManagerEnableIRPrinting, mlirPassManagerEnableVerifier,
-    mlirPassManagerGetAsOpPassManager, mlirPassManagerGetNestedUnder, mlirPassManagerRunOnOp,
-    MlirPassManager,
+    mlirPassManagerGetAsOpPassManager, mlirPassManagerRunOnOp, MlirPassManager,
+};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::{self, Display, Formatter},
+    fs, mem,
+    path::{Path, PathBuf},
+    rc::Rc,
+    slice,
 };
-use std::marker::PhantomData;
 
 /// A pass manager.
+///
+/// Pass timing, IR printing with a configuration, and pass instrumentations
+/// observe passes added after any of them is enabled. Observed passes are
+/// wrapped with no-op external passes, which invalidate all analyses, and a
+/// context runs them in a single thread.
 pub struct PassManager<'c> {
     raw: MlirPassManager,
-    _context: PhantomData<&'c Context>,
+    context: &'c Context,
+    verifier: Cell<bool>,
+    native_ir_printing: Cell<bool>,
+    timing: Cell<bool>,
+    timing_report: RefCell<Option<TimingReport>>,
+    ir_printing: RefCell<Option<IrPrintingConfiguration>>,
+    instrumentations: RefCell<Vec<Box<dyn PassInstrumentation>>>,
+    reproducer_directory: RefCell<Option<PathBuf>>,
+    observer: Rc<Observer>,
 }
 
 impl<'c> PassManager<'c> {
     /// Creates a pass manager.
-    pub fn new(context: &Context) -> Self {
-        Self {
-            raw: unsafe { mlirPassManagerCreate(context.to_raw()) },
-            _context: Default::default(),
+    pub fn new(context: &'c Context) -> Self {
+        unsafe { Self::from_raw(context, mlirPassManagerCreate(context.to_raw()), "any") }
+    }
+
+    /// Creates a pass manager anchored on operations of a given name.
+    pub fn new_on_operation(context: &'c Context, name: &str) -> Self {
+        unsafe {
+            Self::from_raw(
+                context,
+                mlirPassManagerCreateOnOperation(context.to_raw(), StringRef::new(name).to_raw()),
+                name,
+            )
         }
     }
 
     /// Returns an operation pass manager for nested operations corresponding to
     /// a given name.
     pub fn nested_under(&self, name: &str) -> OperationPassManager {
-        let name = StringRef::new(name);
-
-        unsafe {
-            OperationPassManager::from_raw(mlirPassManagerGetNestedUnder(self.raw, name.to_raw()))
-        }
+        self.as_operation_pass_manager().nested_under(name)
     }
 
     /// Adds a pass.
     pub fn add_pass(&self, pass: Pass) {
-        unsafe { mlirPassManagerAddOwnedPass(self.raw, pass.to_raw()) }
+        self.as_operation_pass_manager().add_pass(pass)
     }
 
     /// Enables a verifier.
     pub fn enable_verifier(&self, enabled: bool) {
+        self.verifier.set(enabled);
+
         unsafe { mlirPassManagerEnableVerifier(self.raw, enabled) }
     }
 
     /// Enables IR printing.
     pub fn enable_ir_printing(&self) {
+        self.native_ir_printing.set(true);
+
         unsafe { mlirPassManagerEnableIRPrinting(self.raw) }
     }
 
+    /// Enables IR printing with a configuration.
+    ///
+    /// Only passes added after this call are printed. A configuration set
+    /// while a pass manager runs takes effect from the next run.
+    pub fn enable_ir_printing_with_configuration(&self, configuration: IrPrintingConfiguration) {
+        *self.ir_printing.borrow_mut() = Some(configuration);
+        self.observer.enable();
+    }
+
+    /// Enables pass timing for debugging.
+    ///
+    /// Timings are not representative of production runs. Only passes added
+    /// after this call are timed, and passes added before it are missing in
+    /// reports. Timed passes are wrapped with no-op passes, which invalidate
+    /// all analyses, and a context runs them in a single thread.
+    pub fn enable_timing(&self) {
+        self.timing.set(true);
+        self.observer.enable();
+    }
+
+    /// Adds a pass instrumentation.
+    ///
+    /// Only passes added after this call are instrumented. Hooks are called for
+    /// each operation a pass runs on while a pass manager runs. Instrumentations
+    /// added during a run take effect from the next run.
+    pub fn add_instrumentation(&self, instrumentation: impl PassInstrumentation + 'static) {
+        self.instrumentations
+            .borrow_mut()
+            .push(Box::new(instrumentation));
+        self.observer.enable();
+    }
+
+    /// Enables crash reproducers.
+    ///
+    /// When a run fails, an input operation and a pass pipeline are written
+    /// into `input.mlir` and `pipeline.txt` files in a given directory
+    /// respectively. Existing files are overwritten.
+    pub fn enable_crash_reproducer(&self, directory: impl AsRef<Path>) {
+        *self.reproducer_directory.borrow_mut() = Some(directory.as_ref().into());
+    }
+
+    /// Returns a timing report of the last run.
+    ///
+    /// It is available only when pass timing is enabled. If the last run
+    /// failed, it contains timings of passes up to the failed one.
+    pub fn timing_report(&self) -> Option<TimingReport> {
+        self.timing_report.borrow().clone()
+    }
+
+    /// Returns a statistics report of passes.
+    ///
+    /// It contains statistics of external passes returned by
+    /// [`RunExternalPass::statistics`](super::RunExternalPass::statistics).
+    /// Statistics of native passes are not available through the C API.
+    pub fn statistics_report(&self) -> StatisticsReport {
+        self.observer.statistics_report()
+    }
+
     /// Runs passes added to a pass manager against a module.
     pub fn run(&self, module: &mut Module) -> Result<(), Error> {
-        let result = LogicalResult::from_raw(unsafe {
-            mlirPassManagerRunOnOp(self.raw, module.as_operation().to_raw())
-        });
+        self.run_on_operation(&mut module.as_operation_mut())
+    }
+
+    /// Runs passes added to a pass manager against an operation.
+    ///
+    /// The operation must match an anchor of the pass manager unless the pass
+    /// manager is operation-agnostic.
+    pub fn run_on_operation(&self, operation: &mut Operation) -> Result<(), Error> {
+        let operation = unsafe { OperationRef::from_raw(operation.to_raw()) };
+        let input = if self.reproducer_directory.borrow().is_some() {
+            Some(print_generic_operation(operation)?)
+        } else {
+            None
+        };
+
+        let result = if self.observer.is_enabled() {
+            self.run_observed(operation)
+        } else {
+            Self::run_raw(self.raw, operation)
+        };
+
+        if let (Err(Error::RunPass), Some(input)) = (&result, input) {
+            self.write_reproducer(&input)?;
+        }
+
+        result
+    }
+
+    /// Runs passes one by one on a copy of a module and returns the first pass
+    /// that fails, after which the module fails verification, or after which a
+    /// predicate returns a different result from the one for the original
+    /// module.
+    ///
+    /// All passes in a pipeline must be registered.
+    pub fn bisect(
+        &self,
+        module: &Module,
+        mut predicate: impl FnMut(OperationRef) -> bool,
+    ) -> Result<Option<BisectedPass>, Error> {
+        let pipeline = parse_pipeline(&self.to_string())?;
+        let module = Module::from_operation((*module.as_operation()).clone())
+            .expect("valid module operation");
+        let operation = module.as_operation();
+        let expected = predicate(operation);
+
+        for (index, (path, pass)) in pipeline.passes().into_iter().enumerate() {
+            let manager = self.create_single_pass_manager(&path, pass)?;
+            manager.enable_verifier(false);
+
+            let input = print_generic_operation(operation)?;
+
+            let cause = if Self::run_raw(manager.raw, operation).is_err() {
+                BisectionCause::PassFailure
+            } else if !operation.verify() {
+                BisectionCause::VerificationFailure
+            } else if predicate(operation) != expected {
+                BisectionCause::PredicateFlip
+            } else {
+                continue;
+            };
+
+            return Ok(Some(BisectedPass::new(
+                index,
+                pass.name().into(),
+                path.iter().map(|&name| name.into()).collect(),
+                cause,
+                input,
+            )));
+        }
+
+        Ok(None)
+    }
+
+    /// Converts a pass manager to an operation pass manager.
+    pub fn as_operation_pass_manager(&self) -> OperationPassManager {
+        unsafe {
+            OperationPassManager::from_raw(mlirPassManagerGetAsOpPassManager(self.raw))
+                .with_observer(&self.observer)
+        }
+    }
+
+    unsafe fn from_raw(context: &'c Context, raw: MlirPassManager, anchor: &str) -> Self {
+        let observer = Rc::new(Observer::default());
+
+        observer.set_path(mlirPassManagerGetAsOpPassManager(raw), vec![anchor.into()]);
+
+        Self {
+            raw,
+            context,
+            verifier: Cell::new(true),
+            native_ir_printing: Cell::new(false),
+            timing: Cell::new(false),
+            timing_report: Default::default(),
+            ir_printing: Default::default(),
+            instrumentations: Default::default(),
+            reproducer_directory: Default::default(),
+            observer,
+        }
+    }
+
+    fn run_raw(raw: MlirPassManager, operation: OperationRef) -> Result<(), Error> {
+        let result =
+            LogicalResult::from_raw(unsafe { mlirPassManagerRunOnOp(raw, operation.to_raw()) });
 
         if result.is_success() {
             Ok(())
@@ -64,9 +259,104 @@ impl<'c> PassManager<'c> {
         }
     }
 
-    /// Converts a pass manager to an operation pass manager.
-    pub fn as_operation_pass_manager(&self) -> OperationPassManager {
-        unsafe { OperationPassManager::from_raw(mlirPassManagerGetAsOpPassManager(self.raw)) }
+    fn run_observed(&self, operation: OperationRef) -> Result<(), Error> {
+        self.observer
+            .resolve_names(self.as_operation_pass_manager());
+
+        // Move state out so that callbacks can configure a pass manager.
+        self.observer.start(Run::new(
+            operation,
+            self.timing.get(),
+            self.ir_printing.take(),
+            self.instrumentations.take(),
+        ));
+
+        // Observers are not thread-safe.
+        let multi_threading = self.context.is_multi_threading_enabled();
+
+        if multi_threading {
+            self.context.enable_multi_threading(false);
+        }
+
+        let result = Self::run_raw(self.raw, operation);
+
+        if multi_threading {
+            self.context.enable_multi_threading(true);
+        }
+
+        let run = self.observer.finish(result.is_err());
+
+        if let Some(report) = self.observer.timing_report(&run) {
+            *self.timing_report.borrow_mut() = Some(report);
+        }
+
+        let mut instrumentations = self.instrumentations.borrow_mut();
+        let added = mem::replace(&mut *instrumentations, run.instrumentations);
+        instrumentations.extend(added);
+
+        let mut ir_printing = self.ir_printing.borrow_mut();
+
+        if ir_printing.is_none() {
+            *ir_printing = run.ir_printing;
+        }
+
+        result?;
+
+        run.error.map_or(Ok(()), Err)
+    }
+
+    fn write_reproducer(&self, input: &str) -> Result<(), Error> {
+        let Some(directory) = self.reproducer_directory.borrow().clone() else {
+            return Ok(());
+        };
+
+        (|| {
+            fs::create_dir_all(&directory)?;
+            fs::write(directory.join("input.mlir"), input)?;
+            fs::write(directory.join("pipeline.txt"), self.to_string())
+        })()
+        .map_err(|error| Error::WriteReproducer(error.to_string()))
+    }
+
+    fn create_single_pass_manager(
+        &self,
+        path: &[&str],
+        pass: &PipelineElement,
+    ) -> Result<Self, Error> {
+        let manager = match path[0] {
+            "any" => Self::new(self.context),
+            name => Self::new_on_operation(self.context, name),
+        };
+
+        manager.enable_verifier(self.verifier.get());
+
+        if self.native_ir_printing.get() {
+            manager.enable_ir_printing();
+        }
+
+        let mut operation_manager = manager.as_operation_pass_manager();
+
+        for name in &path[1..] {
+            operation_manager = operation_manager.nested_under(name);
+        }
+
+        add_pipeline_elements(operation_manager, slice::from_ref(pass))?;
+
+        Ok(manager)
+    }
+}
+
+fn print_generic_operation(operation: OperationRef) -> Result<String, Error> {
+    operation.to_string_with_flags(
+        OperationPrintingFlags::new()
+            .enable_debug_info(true, false)
+            .print_generic_operation_form(),
+    )
+}
+
+impl<'c> Display for PassManager<'c> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.as_operation_pass_manager(), formatter)
     }
 }
 
@@ -80,13 +370,23 @@ impl<'c> Drop for PassManager<'c> {
 mod tests {
     use super::*;
     use crate::{
-        ir::{Location, Module},
-        pass::{self, transform::register_print_op_stats},
+        ir::{attribute::StringAttribute, r#type::TypeId, Block, Location, Module},
+        pass::{
+            self, create_external,
+            options::print_options,
+            transform::{register_canonicalizer, register_cse, register_print_op_stats},
+            ExternalPass, PassStatistic, RunExternalPass,
+        },
         test::create_test_context,
-        utility::parse_pass_pipeline,
+        utility::{parse_pass_pipeline, register_external_pass},
+        ContextRef,
     };
     use indoc::indoc;
     use pretty_assertions::assert_eq;
+    use std::{
+        io,
+        rc::{Rc, Weak},
+    };
 
     #[test]
     fn new() {
@@ -191,6 +491,781 @@ mod tests {
         assert_eq!(manager.run(&mut module), Ok(()));
     }
 
+    #[test]
+    fn run_with_timing() {
+        let context = create_test_context();
+        register_cse();
+        register_canonicalizer();
+
+        let mut module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                    %res = arith.addi %arg0, %arg0 : i32
+                    return %res : i32
+                }
+                "
+            ),
+        )
+        .unwrap();
+
+        let manager = PassManager::new(&context);
+        manager.enable_timing();
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert_eq!(manager.timing_report(), None);
+        assert_eq!(manager.run(&mut module), Ok(()));
+
+        let report = manager.timing_report().unwrap();
+
+        assert_eq!(
+            report
+                .passes()
+                .iter()
+                .map(|pass| (pass.name(), pass.nesting()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("cse", &["any".to_owned(), "func.func".to_owned()][..]),
+                ("canonicalize", &["any".to_owned()][..]),
+            ]
+        );
+        assert!(report.to_string().contains("Total Execution Time"));
+        assert_eq!(manager.to_string(), "any(func.func(cse),canonicalize)");
+    }
+
+    #[test]
+    fn run_with_timing_after_passes() {
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+
+        let manager = PassManager::new(&context);
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+        manager.enable_timing();
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_canonicalizer());
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert_eq!(
+            manager
+                .timing_report()
+                .unwrap()
+                .passes()
+                .iter()
+                .map(|pass| pass.name())
+                .collect::<Vec<_>>(),
+            ["canonicalize", "cse"]
+        );
+    }
+
+    #[test]
+    fn run_with_timing_and_external_pass() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static COUNTING_PASS: PassId = PassId;
+
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let count = Rc::new(Cell::new(0));
+
+        let manager = PassManager::new(&context);
+        manager.enable_timing();
+        manager.nested_under("func.func").add_pass(create_external(
+            {
+                let count = count.clone();
+                move |_: OperationRef, _: ExternalPass| count.set(count.get() + 1)
+            },
+            TypeId::create(&COUNTING_PASS),
+            "counting pass",
+            "counting-pass",
+            "a counting pass",
+            "func.func",
+            &[],
+        ));
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert_eq!(count.get(), 1);
+        assert_eq!(
+            manager
+                .timing_report()
+                .unwrap()
+                .passes()
+                .iter()
+                .map(|pass| pass.name())
+                .collect::<Vec<_>>(),
+            ["counting-pass"]
+        );
+    }
+
+    #[test]
+    fn run_with_timing_and_parsed_pipeline() {
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+
+        let manager = PassManager::new(&context);
+        manager.enable_timing();
+        parse_pass_pipeline(
+            manager.as_operation_pass_manager(),
+            "builtin.module(func.func(cse),canonicalize)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            manager.to_string(),
+            "builtin.module(func.func(cse),canonicalize)"
+        );
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert_eq!(
+            manager
+                .timing_report()
+                .unwrap()
+                .passes()
+                .iter()
+                .map(|pass| (pass.name(), pass.nesting()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "cse",
+                    &["builtin.module".to_owned(), "func.func".to_owned()][..]
+                ),
+                ("canonicalize", &["builtin.module".to_owned()][..]),
+            ]
+        );
+    }
+
+    #[test]
+    fn statistics_report() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static COUNTING_PASS: PassId = PassId;
+
+        #[derive(Clone)]
+        struct CountingPass(PassStatistic);
+
+        impl<'c> RunExternalPass<'c> for CountingPass {
+            fn initialize(&mut self, _context: ContextRef<'c>) {}
+
+            fn run(&mut self, _operation: OperationRef<'c, '_>, _pass: ExternalPass<'_>) {
+                self.0.increment();
+            }
+
+            fn statistics(&self) -> Vec<PassStatistic> {
+                vec![self.0.clone()]
+            }
+        }
+
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+
+        let manager = PassManager::new(&context);
+        manager.nested_under("func.func").add_pass(create_external(
+            CountingPass(PassStatistic::new("functions", "Number of functions")),
+            TypeId::create(&COUNTING_PASS),
+            "counting pass",
+            "counting-pass",
+            "a counting pass",
+            "func.func",
+            &[],
+        ));
+        manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+
+        let report = manager.statistics_report();
+
+        assert_eq!(report.passes().len(), 1);
+        assert_eq!(report.passes()[0].name(), "counting-pass");
+        assert_eq!(report.passes()[0].nesting(), ["any", "func.func"]);
+        assert!(report.to_string().ends_with(
+            "'func.func' Pipeline\n  counting-pass\n    (S) 1 functions - Number of functions\n"
+        ));
+    }
+
+    #[test]
+    fn run_without_timing() {
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(
+            manager.run(&mut Module::new(Location::unknown(&context))),
+            Ok(())
+        );
+        assert_eq!(manager.timing_report(), None);
+    }
+
+    fn create_ir_printing_configuration() -> (IrPrintingConfiguration, Rc<RefCell<String>>) {
+        let output = Rc::new(RefCell::new(String::new()));
+        let configuration = IrPrintingConfiguration::with_callback({
+            let output = output.clone();
+            move |string| output.borrow_mut().push_str(string)
+        });
+
+        (configuration, output)
+    }
+
+    fn parse_redundant_module(context: &Context) -> Module {
+        register_cse();
+        register_canonicalizer();
+
+        Module::parse(
+            context,
+            indoc!(
+                "
+                func.func @foo(%arg0 : i32) -> i32 {
+                    %0 = arith.addi %arg0, %arg0 : i32
+                    %1 = arith.addi %arg0, %arg0 : i32
+                    %2 = arith.addi %0, %1 : i32
+                    return %2 : i32
+                }
+                "
+            ),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn run_with_ir_printing() {
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let (configuration, output) = create_ir_printing_configuration();
+
+        let manager = PassManager::new(&context);
+        manager.enable_ir_printing_with_configuration(configuration.print_before(&[]));
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+
+        let output = output.borrow();
+
+        assert!(!output.contains("IR Dump Before"));
+        assert!(output.contains("// -----// IR Dump After cse //----- //\nmodule {"));
+    }
+
+    #[test]
+    fn run_with_ir_printing_in_local_scope() {
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let (configuration, output) = create_ir_printing_configuration();
+
+        let manager = PassManager::new(&context);
+        manager.enable_ir_printing_with_configuration(
+            configuration
+                .print_before_if(|pass| pass == "cse")
+                .print_after(&[])
+                .print_module_scope(false)
+                .flags(OperationPrintingFlags::new().print_generic_operation_form()),
+        );
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+
+        let output = output.borrow();
+
+        assert!(output.starts_with("// -----// IR Dump Before cse //----- //\n\"func.func\"()"));
+        assert!(!output.contains("IR Dump After"));
+    }
+
+    #[test]
+    fn run_with_ir_printing_only_on_change() {
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let (configuration, output) = create_ir_printing_configuration();
+
+        let manager = PassManager::new(&context);
+        manager.enable_ir_printing_with_configuration(
+            configuration.print_before(&[]).print_only_on_change(true),
+        );
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert_eq!(output.borrow().matches("IR Dump After cse").count(), 1);
+    }
+
+    #[test]
+    fn run_with_ir_printing_and_external_pass() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static NO_OP_PASS: PassId = PassId;
+
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let (configuration, output) = create_ir_printing_configuration();
+
+        let manager = PassManager::new(&context);
+        manager.enable_ir_printing_with_configuration(
+            configuration.print_before(&[]).print_module_scope(false),
+        );
+        manager.nested_under("func.func").add_pass(create_external(
+            |_: OperationRef, _: ExternalPass| {},
+            TypeId::create(&NO_OP_PASS),
+            "no-op pass",
+            "no-op-pass",
+            "a no-op pass",
+            "func.func",
+            &[],
+        ));
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert!(output
+            .borrow()
+            .starts_with("// -----// IR Dump After no-op-pass //----- //\nfunc.func @foo("));
+    }
+
+    #[test]
+    fn run_with_ir_printing_into_writer() {
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+
+        let manager = PassManager::new(&context);
+        manager.enable_ir_printing_with_configuration(IrPrintingConfiguration::new(io::sink()));
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+    }
+
+    #[test]
+    fn run_with_instrumentation() {
+        struct Instrumentation(Rc<RefCell<Vec<String>>>);
+
+        fn operation_name(operation: OperationRef) -> String {
+            operation.name().as_string_ref().as_str().unwrap().into()
+        }
+
+        impl PassInstrumentation for Instrumentation {
+            fn run_before_pass(&mut self, pass: &str, operation: OperationRef) {
+                self.0
+                    .borrow_mut()
+                    .push(format!("before {pass} on {}", operation_name(operation)));
+            }
+
+            fn run_after_pass(&mut self, pass: &str, operation: OperationRef) {
+                self.0
+                    .borrow_mut()
+                    .push(format!("after {pass} on {}", operation_name(operation)));
+            }
+        }
+
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let events = Rc::new(RefCell::new(vec![]));
+
+        let manager = PassManager::new(&context);
+        manager.add_instrumentation(Instrumentation(events.clone()));
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert_eq!(
+            *events.borrow(),
+            [
+                "before cse on func.func",
+                "after cse on func.func",
+                "before canonicalize on builtin.module",
+                "after canonicalize on builtin.module",
+            ]
+        );
+    }
+
+    #[test]
+    fn run_with_instrumentation_during_passes() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static RECORDING_PASS: PassId = PassId;
+
+        struct Instrumentation(Rc<RefCell<Vec<String>>>);
+
+        fn function_name(operation: OperationRef) -> String {
+            StringAttribute::try_from(operation.attribute("sym_name").unwrap())
+                .unwrap()
+                .value()
+                .into()
+        }
+
+        impl PassInstrumentation for Instrumentation {
+            fn run_before_pass(&mut self, pass: &str, operation: OperationRef) {
+                self.0
+                    .borrow_mut()
+                    .push(format!("before {pass} on {}", function_name(operation)));
+            }
+
+            fn run_after_pass(&mut self, pass: &str, operation: OperationRef) {
+                self.0
+                    .borrow_mut()
+                    .push(format!("after {pass} on {}", function_name(operation)));
+            }
+        }
+
+        let context = create_test_context();
+        let mut module = Module::parse(
+            &context,
+            indoc!(
+                "
+                func.func @foo() {
+                    return
+                }
+
+                func.func @bar() {
+                    return
+                }
+                "
+            ),
+        )
+        .unwrap();
+        let events = Rc::new(RefCell::new(vec![]));
+
+        let manager = PassManager::new(&context);
+        manager.add_instrumentation(Instrumentation(events.clone()));
+
+        let function_manager = manager.nested_under("func.func");
+        function_manager.add_pass(create_external(
+            {
+                let events = events.clone();
+                move |operation: OperationRef, _: ExternalPass| {
+                    events
+                        .borrow_mut()
+                        .push(format!("run on {}", function_name(operation)))
+                }
+            },
+            TypeId::create(&RECORDING_PASS),
+            "recording pass",
+            "recording-pass",
+            "a recording pass",
+            "func.func",
+            &[],
+        ));
+        function_manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert_eq!(
+            *events.borrow(),
+            [
+                "before recording-pass on foo",
+                "run on foo",
+                "after recording-pass on foo",
+                "before cse on foo",
+                "after cse on foo",
+                "before recording-pass on bar",
+                "run on bar",
+                "after recording-pass on bar",
+                "before cse on bar",
+                "after cse on bar",
+            ]
+        );
+    }
+
+    #[test]
+    fn run_with_instrumentation_on_failure() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static FAILING_PASS: PassId = PassId;
+
+        struct Instrumentation(Rc<RefCell<Vec<String>>>);
+
+        impl PassInstrumentation for Instrumentation {
+            fn run_before_pass(&mut self, pass: &str, _operation: OperationRef) {
+                self.0.borrow_mut().push(format!("before {pass}"));
+            }
+
+            fn run_after_pass(&mut self, pass: &str, _operation: OperationRef) {
+                self.0.borrow_mut().push(format!("after {pass}"));
+            }
+
+            fn run_after_pass_failed(&mut self, pass: &str, _operation: OperationRef) {
+                self.0.borrow_mut().push(format!("after {pass} failed"));
+            }
+        }
+
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let events = Rc::new(RefCell::new(vec![]));
+
+        let manager = PassManager::new(&context);
+        manager.add_instrumentation(Instrumentation(events.clone()));
+        manager.add_pass(pass::transform::create_cse());
+        manager.add_pass(create_external(
+            |_: OperationRef, pass: ExternalPass| pass.signal_failure(),
+            TypeId::create(&FAILING_PASS),
+            "failing pass",
+            "failing-pass",
+            "a failing pass",
+            "",
+            &[],
+        ));
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert_eq!(manager.run(&mut module), Err(Error::RunPass));
+        assert_eq!(
+            *events.borrow(),
+            [
+                "before cse",
+                "after cse",
+                "before failing-pass",
+                "after failing-pass failed",
+            ]
+        );
+    }
+
+    #[test]
+    fn run_with_reentrant_instrumentation() {
+        struct Recorder(Rc<RefCell<Vec<String>>>);
+
+        impl PassInstrumentation for Recorder {
+            fn run_before_pass(&mut self, pass: &str, _operation: OperationRef) {
+                self.0.borrow_mut().push(format!("before {pass}"));
+            }
+
+            fn run_after_pass(&mut self, pass: &str, _operation: OperationRef) {
+                self.0.borrow_mut().push(format!("after {pass}"));
+            }
+        }
+
+        struct Reentrant {
+            manager: Weak<PassManager<'static>>,
+            events: Rc<RefCell<Vec<String>>>,
+            output: Rc<RefCell<String>>,
+        }
+
+        impl PassInstrumentation for Reentrant {
+            fn run_before_pass(&mut self, _pass: &str, _operation: OperationRef) {
+                let manager = self.manager.upgrade().unwrap();
+                let output = self.output.clone();
+
+                manager.add_instrumentation(Recorder(self.events.clone()));
+                manager.enable_ir_printing_with_configuration(
+                    IrPrintingConfiguration::with_callback(move |string| {
+                        output.borrow_mut().push_str(string)
+                    }),
+                );
+            }
+        }
+
+        let context = Box::leak(Box::new(create_test_context()));
+        let mut module = parse_redundant_module(context);
+        let events = Rc::new(RefCell::new(vec![]));
+        let output = Rc::new(RefCell::new(String::new()));
+
+        let manager = Rc::new(PassManager::new(context));
+        manager.add_instrumentation(Reentrant {
+            manager: Rc::downgrade(&manager),
+            events: events.clone(),
+            output: output.clone(),
+        });
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert!(events.borrow().is_empty());
+        assert!(output.borrow().is_empty());
+
+        assert_eq!(manager.run(&mut module), Ok(()));
+        assert_eq!(*events.borrow(), ["before cse", "after cse"]);
+        assert!(output.borrow().contains("IR Dump After cse"));
+    }
+
+    #[test]
+    fn run_with_crash_reproducer() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static FAILING_PASS: PassId = PassId;
+
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let directory = tempfile::tempdir().unwrap();
+
+        let manager = PassManager::new(&context);
+        manager.enable_crash_reproducer(directory.path());
+        manager.add_pass(create_external(
+            |_: OperationRef, pass: ExternalPass| pass.signal_failure(),
+            TypeId::create(&FAILING_PASS),
+            "failing pass",
+            "failing-pass",
+            "a failing pass",
+            "",
+            &[],
+        ));
+
+        assert_eq!(manager.run(&mut module), Err(Error::RunPass));
+        assert_eq!(
+            fs::read_to_string(directory.path().join("input.mlir")).unwrap(),
+            print_generic_operation(module.as_operation()).unwrap()
+        );
+        assert_eq!(
+            fs::read_to_string(directory.path().join("pipeline.txt")).unwrap(),
+            manager.as_operation_pass_manager().to_string()
+        );
+
+        directory.close().unwrap();
+    }
+
+    #[test]
+    fn run_with_crash_reproducer_and_pass_options() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static FAILING_PASS: PassId = PassId;
+
+        register_external_pass("melior-test-failing-pass", |options| {
+            Ok(create_external(
+                |_: OperationRef, pass: ExternalPass| pass.signal_failure(),
+                TypeId::create(&FAILING_PASS),
+                "failing pass",
+                "melior-test-failing-pass",
+                "a failing pass",
+                "",
+                &[],
+            )
+            .with_options(options))
+        });
+
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+        let directory = tempfile::tempdir().unwrap();
+        let pipeline = "builtin.module(melior-test-failing-pass{depth=2})";
+
+        let manager = PassManager::new(&context);
+        manager.enable_crash_reproducer(directory.path());
+        parse_pass_pipeline(manager.as_operation_pass_manager(), pipeline).unwrap();
+
+        assert_eq!(manager.run(&mut module), Err(Error::RunPass));
+
+        let reproducer = fs::read_to_string(directory.path().join("pipeline.txt")).unwrap();
+
+        assert_eq!(reproducer, pipeline);
+
+        let manager = PassManager::new(&context);
+        parse_pass_pipeline(manager.as_operation_pass_manager(), &reproducer).unwrap();
+
+        assert_eq!(manager.to_string(), pipeline);
+
+        directory.close().unwrap();
+    }
+
+    #[test]
+    fn bisect() {
+        let context = create_test_context();
+        let module = parse_redundant_module(&context);
+        let count = |operation: OperationRef| operation.to_string().matches("arith.addi").count();
+
+        let manager = PassManager::new(&context);
+        let function_manager = manager.nested_under("func.func");
+        function_manager.add_pass(pass::transform::create_canonicalizer());
+        function_manager.add_pass(pass::transform::create_cse());
+
+        let bisected = manager
+            .bisect(&module, |operation| count(operation) == 3)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(bisected.index(), 1);
+        assert_eq!(bisected.name(), "cse");
+        assert_eq!(bisected.nesting(), ["any", "func.func"]);
+        assert_eq!(bisected.cause(), BisectionCause::PredicateFlip);
+        assert_eq!(bisected.input().matches("arith.addi").count(), 3);
+        assert_eq!(count(module.as_operation()), 3);
+    }
+
+    #[test]
+    fn bisect_none() {
+        let context = create_test_context();
+        let module = parse_redundant_module(&context);
+
+        let manager = PassManager::new(&context);
+        manager.add_pass(pass::transform::create_canonicalizer());
+
+        assert_eq!(manager.bisect(&module, |_| true), Ok(None));
+    }
+
+    #[test]
+    fn new_on_operation() {
+        let context = create_test_context();
+        let manager = PassManager::new_on_operation(&context, "func.func");
+
+        manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.to_string(), "func.func(cse)");
+    }
+
+    #[test]
+    fn run_on_operation() {
+        let context = create_test_context();
+        let module = parse_redundant_module(&context);
+        let mut block = Block::new(&[]);
+        block.append_operation((*module.body().first_operation().unwrap()).clone());
+
+        let manager = PassManager::new_on_operation(&context, "func.func");
+        manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(
+            manager.run_on_operation(&mut block.first_operation_mut().unwrap()),
+            Ok(())
+        );
+        assert_eq!(
+            block
+                .first_operation()
+                .unwrap()
+                .to_string()
+                .matches("arith.addi")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn run_on_owned_operation() {
+        let context = create_test_context();
+        let module = parse_redundant_module(&context);
+        let mut function = (*module.body().first_operation().unwrap()).clone();
+
+        let manager = PassManager::new_on_operation(&context, "func.func");
+        manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(manager.run_on_operation(&mut function), Ok(()));
+        assert_eq!(function.to_string().matches("arith.addi").count(), 2);
+    }
+
+    #[test]
+    fn run_on_operation_with_mismatched_anchor() {
+        let context = create_test_context();
+        let mut module = parse_redundant_module(&context);
+
+        let manager = PassManager::new_on_operation(&context, "func.func");
+        manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(
+            manager.run_on_operation(&mut module.as_operation_mut()),
+            Err(Error::RunPass)
+        );
+    }
+
     #[test]
     fn print_pass_pipeline() {
         let context = create_test_context();
@@ -209,6 +1284,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display() {
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        assert_eq!(manager.to_string(), "any()");
+
+        manager
+            .nested_under("func.func")
+            .add_pass(pass::transform::create_print_op_stats());
+        manager.add_pass(pass::transform::create_cse());
+
+        assert_eq!(
+            manager.to_string(),
+            "any(func.func(print-op-stats{json=false}),cse)"
+        );
+        assert_eq!(
+            manager.to_string(),
+            manager.as_operation_pass_manager().to_string()
+        );
+    }
+
+    #[test]
+    fn display_unparsable_observed_pipeline() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static PASS_ID: PassId = PassId;
+
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+        manager.enable_timing();
+        manager.add_pass(create_external(
+            |_: OperationRef, _: ExternalPass| {},
+            TypeId::create(&PASS_ID),
+            "unbalanced pass",
+            "unbalanced-pass)",
+            "a pass with an unparsable argument",
+            "",
+            &[],
+        ));
+
+        assert!(manager.to_string().contains("unbalanced-pass)"));
+    }
+
+    #[test]
+    fn display_external_pass_options() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static OPTIONED_PASS: PassId = PassId;
+
+        let context = create_test_context();
+        let create_pass = |depth: &str| {
+            create_external(
+                |_: OperationRef, _: ExternalPass| {},
+                TypeId::create(&OPTIONED_PASS),
+                "optioned pass",
+                "optioned-pass",
+                "an optioned pass",
+                "func.func",
+                &[],
+            )
+            .with_options(&print_options(&[
+                ("depth", depth),
+                ("pipeline", "cse canonicalize"),
+            ]))
+        };
+        let pipeline = "any(func.func(optioned-pass{depth=1 pipeline={cse canonicalize}}),\
+                        func.func(optioned-pass{depth=2 pipeline={cse canonicalize}}),cse)";
+
+        for timing in [false, true] {
+            let manager = PassManager::new(&context);
+
+            if timing {
+                manager.enable_timing();
+            }
+
+            manager.nested_under("func.func").add_pass(create_pass("1"));
+            manager.nested_under("func.func").add_pass(create_pass("2"));
+            manager.add_pass(pass::transform::create_cse());
+
+            assert_eq!(manager.to_string(), pipeline);
+        }
+    }
+
+    #[test]
+    fn round_trip_pass_pipeline() {
+        let context = create_test_context();
+        register_print_op_stats();
+        register_cse();
+        register_canonicalizer();
+
+        for source in [
+            "builtin.module(cse)",
+            "builtin.module(func.func(print-op-stats{json=false}),canonicalize{max-iterations=1})",
+            "builtin.module(func.func(cse),builtin.module(func.func(cse)))",
+        ] {
+            let manager = PassManager::new(&context);
+            parse_pass_pipeline(manager.as_operation_pass_manager(), source).unwrap();
+
+            let other = PassManager::new(&context);
+            parse_pass_pipeline(other.as_operation_pass_manager(), &manager.to_string()).unwrap();
+
+            assert_eq!(other.to_string(), manager.to_string());
+        }
+    }
+
     #[test]
     fn parse_pass_pipeline_() {
         let context = Context::new();
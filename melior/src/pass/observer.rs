@@ -0,0 +1,492 @@
+use super::{
+    create_external, parse_pipeline, ExternalPass, IrPrintingConfiguration, OperationPassManager,
+    PassInstrumentation, PassStatistic, PassStatistics, PassTiming, PipelineElement,
+    RunExternalPass, StatisticsReport, TimingReport,
+};
+use crate::{
+    ir::{r#type::TypeId, OperationRef},
+    ContextRef, Error,
+};
+use mlir_sys::{mlirOpPassManagerAddOwnedPass, MlirOpPassManager, MlirOperation};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// An argument of probe passes.
+pub(crate) const PROBE_ARGUMENT: &str = "melior-pass-observer";
+
+#[repr(align(8))]
+struct ProbeId;
+
+static PROBE_ID: ProbeId = ProbeId;
+
+/// An observer of passes in a pass manager.
+///
+/// When observation is enabled, each pass added to a pass manager is wrapped
+/// with no-op external passes, called probes, which call instrumentations,
+/// print IR, and measure time while the pass manager runs.
+#[derive(Default)]
+pub(crate) struct Observer {
+    enabled: Cell<bool>,
+    // Operation names of operation pass managers from the outermost one.
+    paths: RefCell<HashMap<usize, Vec<String>>>,
+    passes: RefCell<Vec<ObservedPass>>,
+    external_passes: RefCell<Vec<ExternalPassOptions>>,
+    statistics: RefCell<Vec<PassStatistics>>,
+    run: RefCell<Option<Run>>,
+}
+
+struct ObservedPass {
+    name: Option<String>,
+    nesting: Vec<String>,
+    // Whether a pass is cleared from a pass manager.
+    cleared: bool,
+}
+
+// Options of an external pass, which pass pipelines do not print natively.
+struct ExternalPassOptions {
+    nesting: Vec<String>,
+    argument: String,
+    options: Option<String>,
+}
+
+/// A state of a pass manager run.
+pub(crate) struct Run {
+    root: MlirOperation,
+    timings: Option<BTreeMap<usize, Duration>>,
+    pub ir_printing: Option<IrPrintingConfiguration>,
+    pub instrumentations: Vec<Box<dyn PassInstrumentation>>,
+    pending: Vec<PendingPass>,
+    pub error: Option<Error>,
+}
+
+impl Run {
+    pub fn new(
+        root: OperationRef,
+        timing: bool,
+        ir_printing: Option<IrPrintingConfiguration>,
+        instrumentations: Vec<Box<dyn PassInstrumentation>>,
+    ) -> Self {
+        Self {
+            root: root.to_raw(),
+            timings: timing.then(Default::default),
+            ir_printing,
+            instrumentations,
+            pending: vec![],
+            error: None,
+        }
+    }
+
+    fn print(
+        &mut self,
+        operation: OperationRef,
+        print: impl FnOnce(&mut IrPrintingConfiguration, OperationRef) -> Result<Option<String>, Error>,
+    ) -> Option<String> {
+        let configuration = self.ir_printing.as_mut()?;
+        let operation = if configuration.is_module_scope() {
+            unsafe { OperationRef::from_raw(self.root) }
+        } else {
+            operation
+        };
+
+        match print(configuration, operation) {
+            Ok(string) => string,
+            Err(error) => {
+                self.error.get_or_insert(error);
+                None
+            }
+        }
+    }
+}
+
+// A pass which has started on an operation but not finished yet.
+struct PendingPass {
+    index: usize,
+    operation: MlirOperation,
+    previous: Option<String>,
+    start: Instant,
+}
+
+impl Observer {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.get()
+    }
+
+    pub fn enable(&self) {
+        self.enabled.set(true);
+    }
+
+    pub fn path(&self, manager: MlirOpPassManager) -> Vec<String> {
+        self.paths
+            .borrow()
+            .get(&(manager.ptr as usize))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn set_path(&self, manager: MlirOpPassManager, path: Vec<String>) {
+        self.paths.borrow_mut().insert(manager.ptr as usize, path);
+    }
+
+    /// Resets an anchor of an operation pass manager whose passes are cleared.
+    pub fn reset(&self, manager: MlirOpPassManager, name: &str) {
+        let mut path = self.path(manager);
+
+        self.statistics
+            .borrow_mut()
+            .retain(|pass| !pass.nesting().starts_with(&path));
+        self.external_passes
+            .borrow_mut()
+            .retain(|pass| !pass.nesting.starts_with(&path));
+
+        for pass in self
+            .passes
+            .borrow_mut()
+            .iter_mut()
+            .filter(|pass| pass.nesting.starts_with(&path))
+        {
+            pass.cleared = true;
+        }
+
+        path.pop();
+        path.push(name.into());
+        self.set_path(manager, path);
+    }
+
+    /// Records options of an external pass added to an operation pass manager.
+    pub fn add_external_pass(
+        &self,
+        manager: MlirOpPassManager,
+        argument: &str,
+        options: Option<&str>,
+    ) {
+        self.external_passes.borrow_mut().push(ExternalPassOptions {
+            nesting: self.path(manager),
+            argument: argument.into(),
+            options: options.map(String::from),
+        });
+    }
+
+    pub fn has_options(&self) -> bool {
+        self.external_passes
+            .borrow()
+            .iter()
+            .any(|pass| pass.options.is_some())
+    }
+
+    /// Sets options of external passes in a pipeline printed by an operation
+    /// pass manager.
+    ///
+    /// External passes of the same argument under the same operations are
+    /// matched in the order they are added.
+    pub fn set_options(&self, manager: MlirOpPassManager, pipeline: &mut PipelineElement) {
+        let mut options = HashMap::<_, VecDeque<_>>::new();
+
+        for pass in self.external_passes.borrow().iter() {
+            options
+                .entry((pass.nesting.clone(), pass.argument.clone()))
+                .or_default()
+                .push_back(pass.options.clone());
+        }
+
+        if let PipelineElement::Nested { elements, .. } = pipeline {
+            set_options(elements, &mut self.path(manager), &mut options);
+        }
+    }
+
+    /// Adds a probe before a pass if observation is enabled and returns an
+    /// index of the pass.
+    pub fn add_probe(self: &Rc<Self>, manager: OperationPassManager) -> Option<usize> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let mut passes = self.passes.borrow_mut();
+        let index = passes.len();
+
+        passes.push(ObservedPass {
+            name: None,
+            nesting: self.path(manager.to_raw()),
+            cleared: false,
+        });
+        drop(passes);
+
+        self.add_raw_probe(manager, index, false);
+
+        Some(index)
+    }
+
+    /// Finishes adding a pass with a probe after it.
+    ///
+    /// A name of the pass is resolved before a run if it is not given.
+    pub fn finish_pass(
+        self: &Rc<Self>,
+        manager: OperationPassManager,
+        index: Option<usize>,
+        name: Option<&str>,
+        statistics: Vec<PassStatistic>,
+    ) {
+        if let Some(index) = index {
+            self.passes.borrow_mut()[index].name = name.map(String::from);
+            self.add_raw_probe(manager, index, true);
+        }
+
+        if !statistics.is_empty() {
+            self.statistics.borrow_mut().push(PassStatistics::new(
+                name.unwrap_or_default().into(),
+                self.path(manager.to_raw()),
+                statistics,
+            ));
+        }
+    }
+
+    /// Resolves names of observed passes added without them, such as native
+    /// passes created from raw objects, from a pipeline of a pass manager.
+    ///
+    /// An observed pass is the one between a pair of probes. Observed passes
+    /// under the same operations are matched in the order they are added.
+    pub fn resolve_names(&self, manager: OperationPassManager) {
+        let mut passes = self.passes.borrow_mut();
+
+        if passes
+            .iter()
+            .all(|pass| pass.cleared || pass.name.is_some())
+        {
+            return;
+        }
+
+        let Some(pipeline) = manager
+            .print_raw()
+            .ok()
+            .and_then(|string| parse_pipeline(&string).ok())
+        else {
+            return;
+        };
+
+        let mut indices = HashMap::<_, VecDeque<_>>::new();
+
+        for (index, pass) in passes.iter().enumerate() {
+            if !pass.cleared {
+                indices
+                    .entry(pass.nesting.clone())
+                    .or_default()
+                    .push_back(index);
+            }
+        }
+
+        // Paths of operations under which probes before passes are seen last.
+        let mut probed = HashSet::new();
+
+        for (path, element) in pipeline.passes() {
+            let path = path.into_iter().map(String::from).collect::<Vec<_>>();
+
+            if element.name() == PROBE_ARGUMENT {
+                if !probed.remove(&path) {
+                    probed.insert(path);
+                }
+            } else if probed.contains(&path) {
+                if let Some(index) = indices.get_mut(&path).and_then(VecDeque::pop_front) {
+                    passes[index]
+                        .name
+                        .get_or_insert_with(|| element.name().into());
+                }
+            }
+        }
+    }
+
+    pub fn start(&self, run: Run) {
+        *self.run.borrow_mut() = Some(run);
+    }
+
+    /// Finishes a run and notifies failures of passes which have not finished.
+    pub fn finish(&self, failed: bool) -> Run {
+        let mut run = self.run.take().expect("pass manager run");
+
+        if failed {
+            for pass in std::mem::take(&mut run.pending) {
+                run = self.finish_pass_run(run, pass, true);
+            }
+        }
+
+        run
+    }
+
+    pub fn timing_report(&self, run: &Run) -> Option<TimingReport> {
+        let passes = self.passes.borrow();
+        let mut report = TimingReport::default();
+
+        for (&index, &duration) in run.timings.as_ref()? {
+            let pass = &passes[index];
+
+            report.push(PassTiming::new(
+                pass.name.clone().unwrap_or_default(),
+                pass.nesting.clone(),
+                duration,
+            ));
+        }
+
+        Some(report)
+    }
+
+    pub fn statistics_report(&self) -> StatisticsReport {
+        StatisticsReport::new(self.statistics.borrow().clone())
+    }
+
+    fn add_raw_probe(self: &Rc<Self>, manager: OperationPassManager, index: usize, after: bool) {
+        let pass = create_external(
+            Probe {
+                observer: self.clone(),
+                index,
+                after,
+            },
+            TypeId::create(&PROBE_ID),
+            "pass observer",
+            PROBE_ARGUMENT,
+            "an observer of a pass",
+            "",
+            &[],
+        );
+
+        unsafe { mlirOpPassManagerAddOwnedPass(manager.to_raw(), pass.to_raw()) }
+    }
+
+    fn name(&self, index: usize) -> Option<String> {
+        self.passes.borrow()[index].name.clone()
+    }
+
+    fn run_before_pass(&self, index: usize, operation: OperationRef) {
+        let Some(name) = self.name(index) else {
+            return;
+        };
+        // Move a run state out not to borrow it while user callbacks run.
+        let Some(mut run) = self.run.take() else {
+            return;
+        };
+
+        for instrumentation in &mut run.instrumentations {
+            instrumentation.run_before_pass(&name, operation);
+        }
+
+        let previous = run.print(operation, |configuration, operation| {
+            configuration.print_before_pass(&name, operation)
+        });
+
+        run.pending.push(PendingPass {
+            index,
+            operation: operation.to_raw(),
+            previous,
+            start: Instant::now(),
+        });
+
+        *self.run.borrow_mut() = Some(run);
+    }
+
+    fn run_after_pass(&self, index: usize, operation: OperationRef) {
+        let Some(mut run) = self.run.take() else {
+            return;
+        };
+
+        if let Some(position) = run
+            .pending
+            .iter()
+            .position(|pass| pass.index == index && pass.operation.ptr == operation.to_raw().ptr)
+        {
+            let pass = run.pending.remove(position);
+            run = self.finish_pass_run(run, pass, false);
+        }
+
+        *self.run.borrow_mut() = Some(run);
+    }
+
+    fn finish_pass_run(&self, mut run: Run, pass: PendingPass, failed: bool) -> Run {
+        let duration = pass.start.elapsed();
+        let Some(name) = self.name(pass.index) else {
+            return run;
+        };
+        let operation = unsafe { OperationRef::from_raw(pass.operation) };
+
+        if let Some(timings) = &mut run.timings {
+            *timings.entry(pass.index).or_default() += duration;
+        }
+
+        run.print(operation, |configuration, operation| {
+            configuration
+                .print_after_pass(&name, operation, pass.previous.as_deref(), failed)
+                .map(|_| None)
+        });
+
+        for instrumentation in run.instrumentations.iter_mut().rev() {
+            if failed {
+                instrumentation.run_after_pass_failed(&name, operation);
+            } else {
+                instrumentation.run_after_pass(&name, operation);
+            }
+        }
+
+        run
+    }
+}
+
+fn set_options(
+    elements: &mut [PipelineElement],
+    path: &mut Vec<String>,
+    options: &mut HashMap<(Vec<String>, String), VecDeque<Option<String>>>,
+) {
+    for element in elements {
+        match element {
+            PipelineElement::Pass {
+                name,
+                options: pass_options,
+            } => {
+                if let Some(value) = options
+                    .get_mut(&(path.clone(), name.clone()))
+                    .and_then(VecDeque::pop_front)
+                {
+                    if pass_options.is_none() {
+                        *pass_options = value;
+                    }
+                }
+            }
+            PipelineElement::Nested { name, elements } => {
+                path.push(name.clone());
+                set_options(elements, path, options);
+                path.pop();
+            }
+        }
+    }
+}
+
+/// Removes probes from a pipeline.
+pub(crate) fn remove_probes(element: &mut PipelineElement) {
+    if let PipelineElement::Nested { elements, .. } = element {
+        elements.retain(|element| {
+            !matches!(element, PipelineElement::Pass { name, .. } if name == PROBE_ARGUMENT)
+        });
+
+        for element in elements {
+            remove_probes(element);
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Probe {
+    observer: Rc<Observer>,
+    index: usize,
+    after: bool,
+}
+
+impl<'c> RunExternalPass<'c> for Probe {
+    fn initialize(&mut self, _context: ContextRef<'c>) {}
+
+    fn run(&mut self, operation: OperationRef<'c, '_>, _pass: ExternalPass<'_>) {
+        if self.after {
+            self.observer.run_after_pass(self.index, operation);
+        } else {
+            self.observer.run_before_pass(self.index, operation);
+        }
+    }
+}
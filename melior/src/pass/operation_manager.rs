@@ -1,19 +1,29 @@
-use super::PassManager;
-use crate::{pass::Pass, string_ref::StringRef};
+use super::{
+    observer::{remove_probes, Observer},
+    parse_pipeline, PassManager, PassStatistic,
+};
+use crate::{
+    pass::Pass,
+    string_ref::StringRef,
+    utility::{print_callback, print_string_callback},
+    Error,
+};
 use mlir_sys::{
     mlirOpPassManagerAddOwnedPass, mlirOpPassManagerGetNestedUnder, mlirPrintPassPipeline,
-    MlirOpPassManager, MlirStringRef,
+    MlirOpPassManager,
 };
 use std::{
     ffi::c_void,
     fmt::{self, Display, Formatter},
     marker::PhantomData,
+    rc::Rc,
 };
 
 /// An operation pass manager.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 pub struct OperationPassManager<'c, 'a> {
     raw: MlirOpPassManager,
+    observer: Option<&'a Rc<Observer>>,
     _parent: PhantomData<&'a PassManager<'c>>,
 }
 
@@ -21,14 +31,36 @@ impl<'c, 'a> OperationPassManager<'c, 'a> {
     /// Returns an operation pass manager for nested operations corresponding to
     /// a given name.
     pub fn nested_under(&self, name: &str) -> Self {
-        let name = StringRef::new(name);
+        let manager = unsafe {
+            Self::from_raw(mlirOpPassManagerGetNestedUnder(
+                self.raw,
+                StringRef::new(name).to_raw(),
+            ))
+        };
+
+        match self.observer {
+            Some(observer) => {
+                let mut path = observer.path(self.raw);
+                path.push(name.into());
+                observer.set_path(manager.raw, path);
 
-        unsafe { Self::from_raw(mlirOpPassManagerGetNestedUnder(self.raw, name.to_raw())) }
+                manager.with_observer(observer)
+            }
+            None => manager,
+        }
     }
 
     /// Adds a pass.
     pub fn add_pass(&self, pass: Pass) {
+        let index = self.add_probe();
+
         unsafe { mlirOpPassManagerAddOwnedPass(self.raw, pass.to_raw()) }
+
+        if let (Some(observer), Some(argument)) = (self.observer, &pass.argument) {
+            observer.add_external_pass(self.raw, argument, pass.options());
+        }
+
+        self.finish_pass(index, pass.argument.as_deref(), pass.statistics);
     }
 
     /// Converts an operation pass manager into a raw object.
@@ -44,36 +76,102 @@ impl<'c, 'a> OperationPassManager<'c, 'a> {
     pub unsafe fn from_raw(raw: MlirOpPassManager) -> Self {
         Self {
             raw,
+            observer: None,
             _parent: Default::default(),
         }
     }
+
+    pub(crate) fn with_observer(mut self, observer: &'a Rc<Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    pub(crate) fn is_observed(&self) -> bool {
+        self.observer
+            .map(|observer| observer.is_enabled())
+            .unwrap_or_default()
+    }
+
+    /// Resets an anchor of an operation pass manager whose passes are cleared.
+    pub(crate) fn reset(&self, name: &str) {
+        if let Some(observer) = self.observer {
+            observer.reset(self.raw, name);
+        }
+    }
+
+    /// Adds a probe before a pass to be added if observation is enabled.
+    pub(crate) fn add_probe(&self) -> Option<usize> {
+        self.observer?.add_probe(*self)
+    }
+
+    /// Finishes adding a pass.
+    pub(crate) fn finish_pass(
+        &self,
+        index: Option<usize>,
+        name: Option<&str>,
+        statistics: Vec<PassStatistic>,
+    ) {
+        if let Some(observer) = self.observer {
+            observer.finish_pass(*self, index, name, statistics);
+        }
+    }
+
+    /// Prints a pipeline including probes of an observer.
+    pub(crate) fn print_raw(&self) -> Result<String, Error> {
+        let mut data = (String::new(), Ok::<_, Error>(()));
+
+        unsafe {
+            mlirPrintPassPipeline(
+                self.raw,
+                Some(print_string_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
+        }
+
+        data.1?;
+
+        Ok(data.0)
+    }
 }
 
 impl<'c, 'a> Display for OperationPassManager<'c, 'a> {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
-        let mut data = (formatter, Ok(()));
+        if let Some(observer) = self
+            .observer
+            .filter(|observer| observer.is_enabled() || observer.has_options())
+        {
+            // Fall back to a raw pipeline if it cannot be parsed.
+            if let Some(mut pipeline) = self
+                .print_raw()
+                .ok()
+                .and_then(|string| parse_pipeline(&string).ok())
+            {
+                remove_probes(&mut pipeline);
+                observer.set_options(self.raw, &mut pipeline);
 
-        unsafe extern "C" fn callback(string: MlirStringRef, data: *mut c_void) {
-            let data = &mut *(data as *mut (&mut Formatter, fmt::Result));
-            let result = (|| -> fmt::Result {
-                write!(
-                    data.0,
-                    "{}",
-                    StringRef::from_raw(string)
-                        .as_str()
-                        .map_err(|_| fmt::Error)?
-                )
-            })();
-
-            if data.1.is_ok() {
-                data.1 = result;
+                return write!(formatter, "{pipeline}");
             }
         }
 
+        let mut data = (formatter, Ok(()));
+
         unsafe {
-            mlirPrintPassPipeline(self.raw, Some(callback), &mut data as *mut _ as *mut c_void);
+            mlirPrintPassPipeline(
+                self.raw,
+                Some(print_callback),
+                &mut data as *mut _ as *mut c_void,
+            );
         }
 
         data.1
     }
 }
+
+impl<'c, 'a> fmt::Debug for OperationPassManager<'c, 'a> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("OperationPassManager")
+            .field("raw", &self.raw)
+            .finish()
+    }
+}
@@ -0,0 +1,340 @@
+//! Typed pass options.
+
+use crate::Error;
+
+/// Options of a pass.
+///
+/// It can be implemented for passes without typed options in this module.
+/// [`PipelineBuilder::add_pass`](super::PipelineBuilder::add_pass) validates
+/// names and values of options against the pass registry.
+pub trait PassOptions {
+    /// Returns an argument of a pass.
+    fn argument(&self) -> &'static str;
+
+    /// Returns pairs of names and values of options that are set.
+    fn options(&self) -> Vec<(&'static str, String)>;
+}
+
+/// Parses textual pass options, such as `max-iterations=2 top-down`, into
+/// pairs of names and values.
+///
+/// Options without values are parsed as `true`. Quotes and braces around
+/// values are removed.
+pub fn parse_options(source: &str) -> Result<Vec<(String, String)>, Error> {
+    let mut options = vec![];
+    let mut start = None;
+    let mut depth = 0usize;
+    let mut quote = None;
+
+    for (index, character) in source.char_indices().chain([(source.len(), ' ')]) {
+        match (quote, character) {
+            (Some(current), _) if current == character => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(character),
+            (None, '{') => depth += 1,
+            (None, '}') => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    Error::ParsePassPipeline(format!("unbalanced braces in pass options: {source}"))
+                })?
+            }
+            (None, character) if character.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    options.push(parse_option(&source[start..index])?);
+                }
+
+                continue;
+            }
+            _ => {}
+        }
+
+        start.get_or_insert(index);
+    }
+
+    if quote.is_some() || depth > 0 {
+        return Err(Error::ParsePassPipeline(format!(
+            "unterminated value in pass options: {source}"
+        )));
+    }
+
+    Ok(options)
+}
+
+/// Prints pairs of names and values of pass options in a textual form, such
+/// as `max-iterations=2 default-pipeline={cse canonicalize}`.
+///
+/// Values containing whitespace, commas, equal signs, braces, or quotes are
+/// wrapped in braces.
+pub fn print_options<N: AsRef<str>, V: AsRef<str>>(options: &[(N, V)]) -> String {
+    options
+        .iter()
+        .map(|(name, value)| {
+            let (name, value) = (name.as_ref(), value.as_ref());
+
+            if value.contains(|character: char| {
+                character.is_whitespace() || ",={}\"'".contains(character)
+            }) {
+                format!("{name}={{{value}}}")
+            } else {
+                format!("{name}={value}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_option(source: &str) -> Result<(String, String), Error> {
+    let (name, value) = source.split_once('=').unwrap_or((source, "true"));
+
+    if name.is_empty() {
+        return Err(Error::ParsePassPipeline(format!(
+            "pass option name expected: {source}"
+        )));
+    }
+
+    let value = [('"', '"'), ('\'', '\''), ('{', '}')]
+        .into_iter()
+        .find_map(|(start, end)| value.strip_prefix(start)?.strip_suffix(end))
+        .unwrap_or(value);
+
+    Ok((name.into(), value.into()))
+}
+
+macro_rules! pass_options {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $argument:literal,
+        { $($(#[$field_meta:meta])* $field:ident: $type:ty = $option:literal),* $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Default, Eq, PartialEq)]
+        pub struct $name {
+            $($field: Option<$type>,)*
+        }
+
+        impl $name {
+            /// Creates pass options with default values.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            $(
+                $(#[$field_meta])*
+                pub fn $field(mut self, value: $type) -> Self {
+                    self.$field = Some(value);
+                    self
+                }
+            )*
+        }
+
+        impl PassOptions for $name {
+            fn argument(&self) -> &'static str {
+                $argument
+            }
+
+            fn options(&self) -> Vec<(&'static str, String)> {
+                let options: Vec<Option<(&'static str, String)>> = vec![
+                    $(self.$field.as_ref().map(|value| ($option, value.to_string())),)*
+                ];
+
+                options.into_iter().flatten().collect()
+            }
+        }
+    };
+}
+
+pass_options!(
+    /// Options of a `convert-arith-to-llvm` pass.
+    ArithToLlvmOptions, "convert-arith-to-llvm", {
+        /// Sets a bitwidth of an index type.
+        index_bitwidth: u32 = "index-bitwidth",
+    }
+);
+
+pass_options!(
+    /// Options of a `canonicalize` pass.
+    CanonicalizerOptions, "canonicalize", {
+        /// Sets if operations are processed from top to bottom.
+        top_down: bool = "top-down",
+        /// Sets if regions are simplified.
+        region_simplify: bool = "region-simplify",
+        /// Sets a maximum number of iterations.
+        max_iterations: i64 = "max-iterations",
+        /// Sets a maximum number of rewrites per iteration.
+        max_num_rewrites: i64 = "max-num-rewrites",
+        /// Sets if a pass fails when patterns do not converge.
+        test_convergence: bool = "test-convergence",
+    }
+);
+
+pass_options!(
+    /// Options of a `cse` pass.
+    CseOptions, "cse", {}
+);
+
+pass_options!(
+    /// Options of a `finalize-memref-to-llvm` pass.
+    FinalizeMemRefToLlvmOptions, "finalize-memref-to-llvm", {
+        /// Sets if aligned allocation functions are used.
+        use_aligned_alloc: bool = "use-aligned-alloc",
+        /// Sets a bitwidth of an index type.
+        index_bitwidth: u32 = "index-bitwidth",
+        /// Sets if generic allocation functions are used.
+        use_generic_functions: bool = "use-generic-functions",
+        /// Sets if opaque pointers are used.
+        use_opaque_pointers: bool = "use-opaque-pointers",
+    }
+);
+
+pass_options!(
+    /// Options of a `convert-func-to-llvm` pass.
+    FuncToLlvmOptions, "convert-func-to-llvm", {
+        /// Sets if bare pointers are used for memref arguments and results.
+        use_bare_ptr_memref_call_conv: bool = "use-bare-ptr-memref-call-conv",
+        /// Sets a bitwidth of an index type.
+        index_bitwidth: u32 = "index-bitwidth",
+        /// Sets a data layout string.
+        data_layout: String = "data-layout",
+        /// Sets if opaque pointers are used.
+        use_opaque_pointers: bool = "use-opaque-pointers",
+    }
+);
+
+pass_options!(
+    /// Options of an `inline` pass.
+    InlinerOptions, "inline", {
+        /// Sets a default pipeline run on callables.
+        default_pipeline: String = "default-pipeline",
+        /// Sets a maximum number of iterations.
+        max_iterations: u32 = "max-iterations",
+    }
+);
+
+pass_options!(
+    /// Options of a `print-op-stats` pass.
+    PrintOpStatsOptions, "print-op-stats", {
+        /// Sets if statistics are printed in JSON.
+        json: bool = "json",
+    }
+);
+
+pass_options!(
+    /// Options of a `reconcile-unrealized-casts` pass.
+    ReconcileUnrealizedCastsOptions, "reconcile-unrealized-casts", {}
+);
+
+pass_options!(
+    /// Options of a `convert-scf-to-cf` pass.
+    ScfToControlFlowOptions, "convert-scf-to-cf", {}
+);
+
+pass_options!(
+    /// Options of a `symbol-dce` pass.
+    SymbolDceOptions, "symbol-dce", {}
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn argument() {
+        assert_eq!(FuncToLlvmOptions::new().argument(), "convert-func-to-llvm");
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(
+            FuncToLlvmOptions::new()
+                .index_bitwidth(32)
+                .use_bare_ptr_memref_call_conv(true)
+                .options(),
+            vec![
+                ("use-bare-ptr-memref-call-conv", "true".into()),
+                ("index-bitwidth", "32".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_options("").unwrap(), vec![]);
+        assert_eq!(
+            parse_options(" max-iterations=2  top-down ").unwrap(),
+            vec![
+                ("max-iterations".into(), "2".into()),
+                ("top-down".into(), "true".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_quoted_values() {
+        assert_eq!(
+            parse_options(r#"foo="a b" bar='c' baz={cse canonicalize{top-down}}"#).unwrap(),
+            vec![
+                ("foo".into(), "a b".into()),
+                ("bar".into(), "c".into()),
+                ("baz".into(), "cse canonicalize{top-down}".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(matches!(
+            parse_options("foo={bar"),
+            Err(Error::ParsePassPipeline(_))
+        ));
+        assert!(matches!(
+            parse_options("foo=\"bar"),
+            Err(Error::ParsePassPipeline(_))
+        ));
+        assert!(matches!(
+            parse_options("foo=bar}"),
+            Err(Error::ParsePassPipeline(_))
+        ));
+        assert!(matches!(
+            parse_options("=bar"),
+            Err(Error::ParsePassPipeline(_))
+        ));
+    }
+
+    #[test]
+    fn print() {
+        assert_eq!(print_options::<&str, &str>(&[]), "");
+        assert_eq!(
+            print_options(&[
+                ("max-iterations", "2"),
+                ("data-layout", "e-m:e-i64:64"),
+                ("default-pipeline", "cse,canonicalize{top-down=true}"),
+                ("name", "a b"),
+            ]),
+            "max-iterations=2 data-layout=e-m:e-i64:64 \
+             default-pipeline={cse,canonicalize{top-down=true}} name={a b}"
+        );
+    }
+
+    #[test]
+    fn print_and_parse() {
+        let options = [
+            ("foo", "a b"),
+            ("bar", "x=y"),
+            ("baz", "cse,canonicalize{top-down}"),
+        ];
+
+        assert_eq!(
+            parse_options(&print_options(&options)).unwrap(),
+            options
+                .iter()
+                .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn options_empty() {
+        assert_eq!(CseOptions::new().options(), vec![]);
+        assert_eq!(CanonicalizerOptions::new().options(), vec![]);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// An element of a textual pass pipeline.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum PipelineElement {
+    Pass {
+        name: String,
+        options: Option<String>,
+    },
+    Nested {
+        name: String,
+        elements: Vec<PipelineElement>,
+    },
+}
+
+impl PipelineElement {
+    /// Returns a name.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Pass { name, .. } | Self::Nested { name, .. } => name,
+        }
+    }
+
+    /// Returns leaf passes with paths of operation names they are nested under.
+    pub fn passes(&self) -> Vec<(Vec<&str>, &Self)> {
+        let mut passes = vec![];
+
+        self.collect_passes(&mut vec![], &mut passes);
+
+        passes
+    }
+
+    fn collect_passes<'a>(
+        &'a self,
+        path: &mut Vec<&'a str>,
+        passes: &mut Vec<(Vec<&'a str>, &'a Self)>,
+    ) {
+        match self {
+            Self::Pass { .. } => passes.push((path.clone(), self)),
+            Self::Nested { name, elements } => {
+                path.push(name);
+
+                for element in elements {
+                    element.collect_passes(path, passes);
+                }
+
+                path.pop();
+            }
+        }
+    }
+}
+
+impl Display for PipelineElement {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Pass { name, options } => {
+                write!(formatter, "{name}")?;
+
+                if let Some(options) = options {
+                    write!(formatter, "{{{options}}}")?;
+                }
+
+                Ok(())
+            }
+            Self::Nested { name, elements } => fmt_nested(formatter, name, elements),
+        }
+    }
+}
+
+/// Formats a nested pipeline.
+pub(crate) fn fmt_nested(
+    formatter: &mut Formatter,
+    name: &str,
+    elements: &[PipelineElement],
+) -> fmt::Result {
+    write!(formatter, "{name}(")?;
+
+    for (index, element) in elements.iter().enumerate() {
+        if index > 0 {
+            write!(formatter, ",")?;
+        }
+
+        write!(formatter, "{element}")?;
+    }
+
+    write!(formatter, ")")
+}
+
+/// Parses a textual pass pipeline anchored on an operation, such as
+/// `builtin.module(func.func(cse))`.
+pub(crate) fn parse_pipeline(source: &str) -> Result<PipelineElement, Error> {
+    let mut parser = Parser { source, offset: 0 };
+    let element = parser.parse_element()?;
+
+    parser.skip_whitespace();
+
+    if !matches!(element, PipelineElement::Nested { .. }) {
+        return Err(parser.error("operation anchor expected"));
+    } else if parser.offset != source.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+
+    Ok(element)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_elements(&mut self) -> Result<Vec<PipelineElement>, Error> {
+        let mut elements = vec![];
+
+        self.skip_whitespace();
+
+        if self.peek() == Some(')') {
+            return Ok(elements);
+        }
+
+        loop {
+            elements.push(self.parse_element()?);
+            self.skip_whitespace();
+
+            if self.peek() == Some(',') {
+                self.offset += 1;
+            } else {
+                return Ok(elements);
+            }
+        }
+    }
+
+    fn parse_element(&mut self) -> Result<PipelineElement, Error> {
+        self.skip_whitespace();
+
+        let rest = &self.source[self.offset..];
+        let length = rest
+            .find(|character: char| "(){},".contains(character) || character.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..length].to_owned();
+
+        if name.is_empty() {
+            return Err(self.error("pass or operation name expected"));
+        }
+
+        self.offset += length;
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('(') => {
+                self.offset += 1;
+                let elements = self.parse_elements()?;
+                self.expect(')')?;
+
+                Ok(PipelineElement::Nested { name, elements })
+            }
+            Some('{') => Ok(PipelineElement::Pass {
+                name,
+                options: Some(self.parse_options()?),
+            }),
+            _ => Ok(PipelineElement::Pass {
+                name,
+                options: None,
+            }),
+        }
+    }
+
+    fn parse_options(&mut self) -> Result<String, Error> {
+        let start = self.offset + 1;
+        let mut depth = 0;
+        let mut quote = None;
+
+        for (index, character) in self.source[self.offset..].char_indices() {
+            match (quote, character) {
+                (Some(current), _) if current == character => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(character),
+                (None, '{') => depth += 1,
+                (None, '}') => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        let end = self.offset + index;
+                        self.offset = end + 1;
+
+                        return Ok(self.source[start..end].to_owned());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Err(self.error("unbalanced braces in pass options"))
+    }
+
+    fn expect(&mut self, character: char) -> Result<(), Error> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(character) {
+            self.offset += 1;
+
+            Ok(())
+        } else {
+            Err(self.error(&format!("'{character}' expected")))
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.source[self.offset..];
+
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::ParsePassPipeline(format!(
+            "{message} at position {} in `{}`",
+            self.offset, self.source
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn pass(name: &str, options: Option<&str>) -> PipelineElement {
+        PipelineElement::Pass {
+            name: name.into(),
+            options: options.map(String::from),
+        }
+    }
+
+    fn nested(name: &str, elements: Vec<PipelineElement>) -> PipelineElement {
+        PipelineElement::Nested {
+            name: name.into(),
+            elements,
+        }
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(parse_pipeline("any()"), Ok(nested("any", vec![])));
+    }
+
+    #[test]
+    fn parse_nested() {
+        assert_eq!(
+            parse_pipeline(
+                "builtin.module(func.func(print-op-stats{json=false}, cse),canonicalize)"
+            ),
+            Ok(nested(
+                "builtin.module",
+                vec![
+                    nested(
+                        "func.func",
+                        vec![
+                            pass("print-op-stats", Some("json=false")),
+                            pass("cse", None)
+                        ]
+                    ),
+                    pass("canonicalize", None),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_nested_options() {
+        assert_eq!(
+            parse_pipeline("any(foo{bar={baz=1} qux=\"}\"})"),
+            Ok(nested(
+                "any",
+                vec![pass("foo", Some("bar={baz=1} qux=\"}\""))]
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(parse_pipeline("cse").is_err());
+        assert!(parse_pipeline("any(cse").is_err());
+        assert!(parse_pipeline("any(cse{)").is_err());
+        assert!(parse_pipeline("any(cse))").is_err());
+        assert!(parse_pipeline("any(,)").is_err());
+    }
+
+    #[test]
+    fn display() {
+        for source in [
+            "any()",
+            "builtin.module(func.func(print-op-stats{json=false},cse),canonicalize)",
+        ] {
+            assert_eq!(parse_pipeline(source).unwrap().to_string(), source);
+        }
+    }
+
+    #[test]
+    fn passes() {
+        let pipeline = parse_pipeline("builtin.module(func.func(cse),canonicalize)").unwrap();
+
+        assert_eq!(
+            pipeline.passes(),
+            vec![
+                (vec!["builtin.module", "func.func"], &pass("cse", None)),
+                (vec!["builtin.module"], &pass("canonicalize", None)),
+            ]
+        );
+    }
+}
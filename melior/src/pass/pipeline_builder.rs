@@ -0,0 +1,273 @@
+use super::{
+    options::{print_options, PassOptions},
+    pipeline::{fmt_nested, PipelineElement},
+    PassManager,
+};
+use crate::{utility::parse_pass_pipeline, Context, Error};
+use std::fmt::{self, Display, Formatter};
+
+/// A pass pipeline builder with typed pass options.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PipelineBuilder {
+    anchor: String,
+    elements: Vec<PipelineElement>,
+}
+
+impl PipelineBuilder {
+    /// Creates a pipeline builder anchored on operations of a given name.
+    pub fn new(anchor: &str) -> Self {
+        Self {
+            anchor: anchor.into(),
+            elements: vec![],
+        }
+    }
+
+    /// Adds a pass with options.
+    ///
+    /// The pass and its options are validated against the pass registry.
+    /// Therefore, the pass must be registered.
+    pub fn add_pass(mut self, options: impl PassOptions) -> Result<Self, Error> {
+        let argument = options.argument();
+        let values = options.options();
+
+        validate_pass(argument, &values)?;
+
+        self.elements.push(PipelineElement::Pass {
+            name: argument.into(),
+            options: (!values.is_empty()).then(|| print_options(&values)),
+        });
+
+        Ok(self)
+    }
+
+    /// Adds a pipeline for nested operations corresponding to a given name.
+    pub fn nested_under(
+        mut self,
+        name: &str,
+        build: impl FnOnce(Self) -> Result<Self, Error>,
+    ) -> Result<Self, Error> {
+        let builder = build(Self::new(name))?;
+
+        self.elements.push(PipelineElement::Nested {
+            name: builder.anchor,
+            elements: builder.elements,
+        });
+
+        Ok(self)
+    }
+
+    /// Builds a pass manager.
+    ///
+    /// Nesting of passes is validated while a pipeline is parsed.
+    pub fn build<'c>(&self, context: &'c Context) -> Result<PassManager<'c>, Error> {
+        let manager = PassManager::new(context);
+
+        parse_pass_pipeline(manager.as_operation_pass_manager(), &self.to_string())?;
+
+        Ok(manager)
+    }
+}
+
+thread_local! {
+    // The pass registry is global. So any context can validate passes.
+    static VALIDATION_CONTEXT: Context = {
+        let context = Context::new();
+        context.enable_multi_threading(false);
+        context
+    };
+}
+
+fn validate_pass(argument: &str, options: &[(&str, String)]) -> Result<(), Error> {
+    VALIDATION_CONTEXT.with(|context| {
+        let parse = |options: &str| {
+            parse_pass_pipeline(
+                PassManager::new(context).as_operation_pass_manager(),
+                &format!("any({argument}{options})"),
+            )
+        };
+
+        parse("")?;
+
+        // Validate options one by one to find an invalid one.
+        for (name, value) in options {
+            parse(&format!("{{{}}}", print_options(&[(name, value)]))).map_err(|error| {
+                Error::InvalidPassOption {
+                    pass: argument.into(),
+                    option: (*name).into(),
+                    message: match error {
+                        Error::ParsePassPipeline(message) => message,
+                        error => error.to_string(),
+                    },
+                }
+            })?;
+        }
+
+        Ok(())
+    })
+}
+
+impl Display for PipelineBuilder {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        fmt_nested(formatter, &self.anchor, &self.elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pass::{
+            options::{
+                parse_options, CanonicalizerOptions, CseOptions, FuncToLlvmOptions, InlinerOptions,
+            },
+            parse_pipeline,
+        },
+        test::create_test_context,
+        utility::register_all_passes,
+    };
+    use pretty_assertions::assert_eq;
+
+    struct UnknownOptions;
+
+    impl PassOptions for UnknownOptions {
+        fn argument(&self) -> &'static str {
+            "canonicalize"
+        }
+
+        fn options(&self) -> Vec<(&'static str, String)> {
+            vec![
+                ("max-iterations", "2".into()),
+                ("max-iteration", "2".into()),
+            ]
+        }
+    }
+
+    fn create_builder() -> PipelineBuilder {
+        register_all_passes();
+
+        PipelineBuilder::new("builtin.module")
+            .nested_under("func.func", |builder| {
+                builder
+                    .add_pass(CseOptions::new())?
+                    .add_pass(CanonicalizerOptions::new().max_iterations(2))
+            })
+            .unwrap()
+            .add_pass(
+                FuncToLlvmOptions::new()
+                    .use_bare_ptr_memref_call_conv(true)
+                    .index_bitwidth(32),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(PipelineBuilder::new("any").to_string(), "any()");
+        assert_eq!(
+            create_builder().to_string(),
+            "builtin.module(func.func(cse,canonicalize{max-iterations=2}),\
+             convert-func-to-llvm{use-bare-ptr-memref-call-conv=true index-bitwidth=32})"
+        );
+    }
+
+    #[test]
+    fn display_string_options() {
+        register_all_passes();
+
+        assert_eq!(
+            PipelineBuilder::new("builtin.module")
+                .add_pass(InlinerOptions::new().default_pipeline("cse,canonicalize".into()))
+                .unwrap()
+                .add_pass(FuncToLlvmOptions::new().data_layout("e-m:e-i64:64".into()))
+                .unwrap()
+                .to_string(),
+            "builtin.module(inline{default-pipeline={cse,canonicalize}},\
+             convert-func-to-llvm{data-layout=e-m:e-i64:64})"
+        );
+    }
+
+    #[test]
+    fn parse_string_options() {
+        register_all_passes();
+
+        let builder = PipelineBuilder::new("builtin.module")
+            .add_pass(InlinerOptions::new().default_pipeline("cse, canonicalize".into()))
+            .unwrap();
+
+        let PipelineElement::Nested { elements, .. } =
+            parse_pipeline(&builder.to_string()).unwrap()
+        else {
+            unreachable!()
+        };
+        let PipelineElement::Pass {
+            options: Some(options),
+            ..
+        } = &elements[0]
+        else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            parse_options(options).unwrap(),
+            [("default-pipeline".into(), "cse, canonicalize".into())]
+        );
+    }
+
+    #[test]
+    fn build() {
+        let context = create_test_context();
+
+        let manager = create_builder().build(&context).unwrap();
+        let pipeline = manager.to_string();
+
+        assert!(pipeline.starts_with("builtin.module(func.func(cse,canonicalize{"));
+        assert!(pipeline.contains("max-iterations=2"));
+        assert!(pipeline.contains("index-bitwidth=32"));
+        assert!(pipeline.contains("use-bare-ptr-memref-call-conv=true"));
+    }
+
+    #[test]
+    fn add_pass_with_unknown_option() {
+        register_all_passes();
+
+        assert!(matches!(
+            PipelineBuilder::new("builtin.module").add_pass(UnknownOptions),
+            Err(Error::InvalidPassOption { pass, option, .. })
+                if pass == "canonicalize" && option == "max-iteration"
+        ));
+    }
+
+    #[test]
+    fn add_unregistered_pass() {
+        struct UnregisteredOptions;
+
+        impl PassOptions for UnregisteredOptions {
+            fn argument(&self) -> &'static str {
+                "melior-unregistered-pass"
+            }
+
+            fn options(&self) -> Vec<(&'static str, String)> {
+                vec![]
+            }
+        }
+
+        assert!(matches!(
+            PipelineBuilder::new("builtin.module").add_pass(UnregisteredOptions),
+            Err(Error::ParsePassPipeline(_))
+        ));
+    }
+
+    #[test]
+    fn add_nested_pass_with_unknown_option() {
+        register_all_passes();
+
+        assert!(matches!(
+            PipelineBuilder::new("builtin.module").nested_under("func.func", |builder| {
+                builder
+                    .add_pass(CseOptions::new())?
+                    .add_pass(UnknownOptions)
+            }),
+            Err(Error::InvalidPassOption { .. })
+        ));
+    }
+}
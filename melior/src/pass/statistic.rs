@@ -0,0 +1,218 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// A named statistic of an external pass.
+///
+/// Clones share a counter. Therefore, values are accumulated across clones of
+/// a pass run on different operations, possibly in different threads.
+#[derive(Clone, Debug)]
+pub struct PassStatistic {
+    name: &'static str,
+    description: &'static str,
+    value: Arc<AtomicU64>,
+}
+
+impl PassStatistic {
+    /// Creates a statistic.
+    pub fn new(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            description,
+            value: Default::default(),
+        }
+    }
+
+    /// Returns a name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns a description.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns a value.
+    pub fn value(&self) -> u64 {
+        self.value.load(Ordering::Relaxed)
+    }
+
+    /// Increments a value.
+    pub fn increment(&self) {
+        self.add(1);
+    }
+
+    /// Adds a value.
+    pub fn add(&self, value: u64) {
+        self.value.fetch_add(value, Ordering::Relaxed);
+    }
+}
+
+impl Display for PassStatistic {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} {} - {}",
+            self.value(),
+            self.name,
+            self.description
+        )
+    }
+}
+
+/// Statistics of a pass.
+#[derive(Clone, Debug)]
+pub struct PassStatistics {
+    name: String,
+    nesting: Vec<String>,
+    statistics: Vec<PassStatistic>,
+}
+
+impl PassStatistics {
+    pub(crate) fn new(name: String, nesting: Vec<String>, statistics: Vec<PassStatistic>) -> Self {
+        Self {
+            name,
+            nesting,
+            statistics,
+        }
+    }
+
+    /// Returns a pass name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns names of operations a pass is nested under from the outermost
+    /// one.
+    pub fn nesting(&self) -> &[String] {
+        &self.nesting
+    }
+
+    /// Returns statistics.
+    pub fn statistics(&self) -> &[PassStatistic] {
+        &self.statistics
+    }
+}
+
+/// A statistics report of passes in a pass manager.
+#[derive(Clone, Debug, Default)]
+pub struct StatisticsReport {
+    passes: Vec<PassStatistics>,
+}
+
+impl StatisticsReport {
+    pub(crate) fn new(passes: Vec<PassStatistics>) -> Self {
+        Self { passes }
+    }
+
+    /// Returns statistics of passes in pipeline order.
+    pub fn passes(&self) -> &[PassStatistics] {
+        &self.passes
+    }
+}
+
+impl Display for StatisticsReport {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        writeln!(formatter, "{:=<80}", "===")?;
+        writeln!(formatter, "{:^80}", "... Pass statistics report ...")?;
+        writeln!(formatter, "{:=<80}", "===")?;
+
+        let mut previous: &[String] = &[];
+
+        for pass in &self.passes {
+            // The outermost operation is an anchor of a whole pipeline.
+            let nesting = pass.nesting.get(1..).unwrap_or_default();
+            let common = previous
+                .iter()
+                .zip(nesting)
+                .take_while(|(one, other)| one == other)
+                .count();
+
+            for (depth, name) in nesting.iter().enumerate().skip(common) {
+                writeln!(
+                    formatter,
+                    "{:indent$}'{name}' Pipeline",
+                    "",
+                    indent = 2 * depth
+                )?;
+            }
+
+            let indent = 2 * nesting.len();
+
+            writeln!(formatter, "{:indent$}{}", "", pass.name)?;
+
+            for statistic in &pass.statistics {
+                writeln!(formatter, "{:indent$}  (S) {statistic}", "")?;
+            }
+
+            previous = nesting;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn add() {
+        let statistic = PassStatistic::new("foo", "Number of foos");
+
+        statistic.increment();
+        statistic.add(2);
+
+        assert_eq!(statistic.value(), 3);
+    }
+
+    #[test]
+    fn clone() {
+        let statistic = PassStatistic::new("foo", "Number of foos");
+        let clone = statistic.clone();
+
+        clone.increment();
+
+        assert_eq!(statistic.value(), 1);
+    }
+
+    #[test]
+    fn display() {
+        let statistic = PassStatistic::new("foo", "Number of foos");
+
+        statistic.add(42);
+
+        assert_eq!(statistic.to_string(), "42 foo - Number of foos");
+    }
+
+    #[test]
+    fn display_report() {
+        let statistic = PassStatistic::new("foo", "Number of foos");
+
+        statistic.add(42);
+
+        let report = StatisticsReport::new(vec![
+            PassStatistics::new(
+                "foo-pass".into(),
+                vec!["builtin.module".into(), "func.func".into()],
+                vec![statistic],
+            ),
+            PassStatistics::new(
+                "bar-pass".into(),
+                vec!["builtin.module".into()],
+                vec![PassStatistic::new("bar", "Number of bars")],
+            ),
+        ]);
+
+        assert!(report.to_string().ends_with(
+            "'func.func' Pipeline\n  foo-pass\n    (S) 42 foo - Number of foos\nbar-pass\n  (S) 0 \
+             bar - Number of bars\n"
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+/// A timing of a pass.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PassTiming {
+    name: String,
+    nesting: Vec<String>,
+    duration: Duration,
+}
+
+impl PassTiming {
+    pub(crate) fn new(name: String, nesting: Vec<String>, duration: Duration) -> Self {
+        Self {
+            name,
+            nesting,
+            duration,
+        }
+    }
+
+    /// Returns a pass name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns names of operations a pass is nested under from the outermost
+    /// one.
+    pub fn nesting(&self) -> &[String] {
+        &self.nesting
+    }
+
+    /// Returns a wall time.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// A timing report of passes run by a pass manager.
+///
+/// It is meant for debugging. See
+/// [`PassManager::enable_timing`](super::PassManager::enable_timing) for how
+/// timings differ from ones of production runs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TimingReport {
+    passes: Vec<PassTiming>,
+}
+
+impl TimingReport {
+    /// Returns timings of passes in execution order.
+    pub fn passes(&self) -> &[PassTiming] {
+        &self.passes
+    }
+
+    /// Returns a total wall time.
+    pub fn total(&self) -> Duration {
+        self.passes.iter().map(PassTiming::duration).sum()
+    }
+
+    pub(crate) fn push(&mut self, timing: PassTiming) {
+        self.passes.push(timing);
+    }
+}
+
+impl Display for TimingReport {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let total = self.total();
+        let line = |formatter: &mut Formatter, duration: Duration, depth: usize, name: &str| {
+            let percentage = if total.is_zero() {
+                0.0
+            } else {
+                100.0 * duration.as_secs_f64() / total.as_secs_f64()
+            };
+
+            writeln!(
+                formatter,
+                "  {:>9.4} ({:>5.1}%)  {:indent$}{name}",
+                duration.as_secs_f64(),
+                percentage,
+                "",
+                indent = 2 * depth,
+            )
+        };
+
+        writeln!(formatter, "{:=<80}", "===")?;
+        writeln!(formatter, "{:^80}", "... Execution time report ...")?;
+        writeln!(formatter, "{:=<80}", "===")?;
+        writeln!(
+            formatter,
+            "  Total Execution Time: {:.4} seconds",
+            total.as_secs_f64()
+        )?;
+        writeln!(formatter)?;
+        writeln!(formatter, "  ----Wall Time----  ----Name----")?;
+
+        let mut previous: &[String] = &[];
+
+        for (index, pass) in self.passes.iter().enumerate() {
+            // The outermost operation is an anchor of a whole pipeline.
+            let nesting = pass.nesting.get(1..).unwrap_or_default();
+            let common = previous
+                .iter()
+                .zip(nesting)
+                .take_while(|(one, other)| one == other)
+                .count();
+
+            for depth in common..nesting.len() {
+                let prefix = &nesting[..=depth];
+                let duration = self.passes[index..]
+                    .iter()
+                    .take_while(|pass| {
+                        pass.nesting
+                            .get(1..)
+                            .unwrap_or_default()
+                            .starts_with(prefix)
+                    })
+                    .map(PassTiming::duration)
+                    .sum();
+
+                line(formatter, duration, depth, &nesting[depth])?;
+            }
+
+            line(formatter, pass.duration, nesting.len(), &pass.name)?;
+            previous = nesting;
+        }
+
+        line(formatter, total, 0, "Total")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn report() -> TimingReport {
+        let mut report = TimingReport::default();
+
+        for (name, nesting, milliseconds) in [
+            ("cse", &["builtin.module", "func.func"][..], 1),
+            ("canonicalize", &["builtin.module", "func.func"], 2),
+            ("inline", &["builtin.module"], 3),
+        ] {
+            report.push(PassTiming::new(
+                name.into(),
+                nesting.iter().map(|&name| name.into()).collect(),
+                Duration::from_millis(milliseconds),
+            ));
+        }
+
+        report
+    }
+
+    #[test]
+    fn total() {
+        assert_eq!(report().total(), Duration::from_millis(6));
+    }
+
+    #[test]
+    fn pass_timing() {
+        let report = report();
+        let timing = &report.passes()[0];
+
+        assert_eq!(timing.name(), "cse");
+        assert_eq!(timing.nesting(), ["builtin.module", "func.func"]);
+        assert_eq!(timing.duration(), Duration::from_millis(1));
+    }
+
+    #[test]
+    fn display() {
+        let string = report().to_string();
+
+        assert!(string.contains("Total Execution Time: 0.0060 seconds"));
+        assert!(string.contains("     0.0030 ( 50.0%)  func.func\n"));
+        assert!(string.contains("     0.0010 ( 16.7%)    cse\n"));
+        assert!(string.contains("     0.0030 ( 50.0%)  inline\n"));
+        assert!(string.ends_with("     0.0060 (100.0%)  Total\n"));
+    }
+}
@@ -1,19 +1,29 @@
 //! Utility functions.
 
 use crate::{
-    context::Context, dialect::DialectRegistry, logical_result::LogicalResult, pass,
-    string_ref::StringRef, Error,
+    context::Context,
+    dialect::DialectRegistry,
+    logical_result::LogicalResult,
+    pass::{self, parse_pipeline, Pass, PipelineElement},
+    string_ref::StringRef,
+    Error,
 };
+use dashmap::DashMap;
 use mlir_sys::{
-    mlirParsePassPipeline, mlirRegisterAllDialects, mlirRegisterAllLLVMTranslations,
-    mlirRegisterAllPasses, MlirStringRef,
+    mlirOpPassManagerAddPipeline, mlirParsePassPipeline, mlirRegisterAllDialects,
+    mlirRegisterAllLLVMTranslations, mlirRegisterAllPasses, MlirStringRef,
 };
+use once_cell::sync::Lazy;
 use std::{
     ffi::c_void,
     fmt::{self, Formatter},
-    sync::Once,
+    sync::{Arc, Once},
 };
 
+type ExternalPassFactory = Arc<dyn Fn(&str) -> Result<Pass, Error> + Send + Sync>;
+
+static EXTERNAL_PASSES: Lazy<DashMap<String, ExternalPassFactory>> = Lazy::new(Default::default);
+
 /// Registers all dialects to a dialect registry.
 pub fn register_all_dialects(registry: &DialectRegistry) {
     unsafe { mlirRegisterAllDialects(registry.to_raw()) }
@@ -32,8 +42,95 @@ pub fn register_all_passes() {
     ONCE.call_once(|| unsafe { mlirRegisterAllPasses() });
 }
 
+/// Registers an external pass by an argument.
+///
+/// Pass pipelines parsed by [`parse_pass_pipeline`] can refer to the pass by
+/// the argument. A factory receives pass options in a textual form, which is
+/// empty if no option is given. Printed pass pipelines keep the options unless
+/// the factory sets others with [`Pass::with_options`].
+pub fn register_external_pass(
+    argument: &str,
+    factory: impl Fn(&str) -> Result<Pass, Error> + Send + Sync + 'static,
+) {
+    EXTERNAL_PASSES.insert(argument.into(), Arc::new(factory));
+}
+
+fn create_external_pass(argument: &str, options: &str) -> Option<Result<Pass, Error>> {
+    // Clone a factory not to hold a lock of the registry while it runs.
+    let factory = EXTERNAL_PASSES.get(argument)?.clone();
+
+    Some(factory(options))
+}
+
+fn contains_external_pass(element: &PipelineElement) -> bool {
+    element
+        .passes()
+        .iter()
+        .any(|(_, pass)| EXTERNAL_PASSES.contains_key(pass.name()))
+}
+
 /// Parses a pass pipeline.
+///
+/// The pipeline can contain external passes registered by
+/// [`register_external_pass`].
 pub fn parse_pass_pipeline(manager: pass::OperationPassManager, source: &str) -> Result<(), Error> {
+    match parse_pipeline(source) {
+        Ok(PipelineElement::Nested { name, elements })
+            if manager.is_observed()
+                || !EXTERNAL_PASSES.is_empty() && elements.iter().any(contains_external_pass) =>
+        {
+            // Set an anchor of a pass manager and clear its passes.
+            parse_native_pass_pipeline(manager, &format!("{name}()"))?;
+            manager.reset(&name);
+            add_pipeline_elements(manager, &elements)
+        }
+        _ => parse_native_pass_pipeline(manager, source),
+    }
+}
+
+/// Adds pass pipeline elements, which can contain external passes, to an
+/// operation pass manager.
+///
+/// Passes are added one by one if the operation pass manager is observed.
+pub(crate) fn add_pipeline_elements(
+    manager: pass::OperationPassManager,
+    elements: &[PipelineElement],
+) -> Result<(), Error> {
+    for element in elements {
+        match element {
+            PipelineElement::Nested { name, elements }
+                if manager.is_observed() || contains_external_pass(element) =>
+            {
+                add_pipeline_elements(manager.nested_under(name), elements)?
+            }
+            PipelineElement::Pass { name, options } => {
+                match create_external_pass(name, options.as_deref().unwrap_or_default()) {
+                    Some(pass) => {
+                        let pass = pass?;
+
+                        manager.add_pass(match pass.options() {
+                            Some(_) => pass,
+                            None => pass.with_options(options.as_deref().unwrap_or_default()),
+                        })
+                    }
+                    None => {
+                        let index = manager.add_probe();
+                        add_pass_pipeline(manager, &element.to_string())?;
+                        manager.finish_pass(index, Some(name), vec![]);
+                    }
+                }
+            }
+            PipelineElement::Nested { .. } => add_pass_pipeline(manager, &element.to_string())?,
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_native_pass_pipeline(
+    manager: pass::OperationPassManager,
+    source: &str,
+) -> Result<(), Error> {
     let mut error_message = None;
 
     let result = LogicalResult::from_raw(unsafe {
@@ -45,6 +142,26 @@ pub fn parse_pass_pipeline(manager: pass::OperationPassManager, source: &str) ->
         )
     });
 
+    into_parse_result(result, error_message)
+}
+
+/// Parses pass pipeline elements and adds them to an operation pass manager.
+fn add_pass_pipeline(manager: pass::OperationPassManager, source: &str) -> Result<(), Error> {
+    let mut error_message = None;
+
+    let result = LogicalResult::from_raw(unsafe {
+        mlirOpPassManagerAddPipeline(
+            manager.to_raw(),
+            StringRef::new(source).to_raw(),
+            Some(handle_parse_error),
+            &mut error_message as *mut _ as *mut _,
+        )
+    });
+
+    into_parse_result(result, error_message)
+}
+
+fn into_parse_result(result: LogicalResult, error_message: Option<String>) -> Result<(), Error> {
     if result.is_success() {
         Ok(())
     } else {
@@ -100,6 +217,15 @@ pub(crate) unsafe extern "C" fn print_string_callback(string: MlirStringRef, dat
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        ir::{r#type::TypeId, Module, OperationRef},
+        pass::{create_external, transform::register_cse, ExternalPass, PassManager},
+        test::create_test_context,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
 
     #[test]
     fn register_dialects() {
@@ -148,4 +274,79 @@ mod tests {
             register_all_passes();
         }
     }
+
+    #[test]
+    fn parse_pass_pipeline_with_external_pass() {
+        #[repr(align(8))]
+        struct PassId;
+
+        static PASS_ID: PassId = PassId;
+        static RUN_COUNT: AtomicUsize = AtomicUsize::new(0);
+        static OPTIONS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        register_cse();
+        register_external_pass("melior-test-external-pass", |options| {
+            OPTIONS.lock().unwrap().push(options.into());
+
+            Ok(create_external(
+                |_: OperationRef, _: ExternalPass| {
+                    RUN_COUNT.fetch_add(1, Ordering::SeqCst);
+                },
+                TypeId::create(&PASS_ID),
+                "test pass",
+                "melior-test-external-pass",
+                "a test pass",
+                "func.func",
+                &[],
+            ))
+        });
+
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        parse_pass_pipeline(
+            manager.as_operation_pass_manager(),
+            "builtin.module(func.func(melior-test-external-pass{foo=42},cse))",
+        )
+        .unwrap();
+
+        assert_eq!(*OPTIONS.lock().unwrap(), ["foo=42"]);
+        assert_eq!(
+            manager.to_string(),
+            "builtin.module(func.func(melior-test-external-pass{foo=42},cse))"
+        );
+
+        let other = PassManager::new(&context);
+
+        parse_pass_pipeline(other.as_operation_pass_manager(), &manager.to_string()).unwrap();
+
+        assert_eq!(*OPTIONS.lock().unwrap(), ["foo=42", "foo=42"]);
+        assert_eq!(other.to_string(), manager.to_string());
+
+        let mut module = Module::parse(&context, "func.func @foo() { return }").unwrap();
+
+        manager.run(&mut module).unwrap();
+
+        assert_eq!(RUN_COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn parse_pass_pipeline_with_failing_external_pass_factory() {
+        register_external_pass("melior-test-failing-external-pass", |options| {
+            Err(Error::ParsePassPipeline(format!(
+                "invalid options: {options}"
+            )))
+        });
+
+        let context = create_test_context();
+        let manager = PassManager::new(&context);
+
+        assert_eq!(
+            parse_pass_pipeline(
+                manager.as_operation_pass_manager(),
+                "builtin.module(melior-test-failing-external-pass{foo})",
+            ),
+            Err(Error::ParsePassPipeline("invalid options: foo".into()))
+        );
+    }
 }